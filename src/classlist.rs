@@ -0,0 +1,93 @@
+use web_sys::Element;
+
+/// Add or remove a class depending on a condition
+///
+/// This function is equivalent to javascript's [`element.classList.toggle(class, condition)`](https://developer.mozilla.org/en-US/docs/Web/API/DOMTokenList/toggle) method.
+/// The class is added if `condition` is `true` and removed if it's `false`, so you don't need to branch on the condition yourself.
+///
+///
+/// # Arguments
+///
+/// * `el` - The element to change
+///
+/// * `class` - The class name to add or remove
+///
+/// * `condition` - Whether the element should have the class
+///
+///
+/// # Panics
+///
+/// * This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+/// * This function will panic if the `class` is empty or contains whitespace
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use webru::{create_element, toggle_class_if};
+///
+/// let tab = create_element("li");
+/// let is_active = true;
+///
+/// toggle_class_if(&tab, "active", is_active);
+///
+/// assert_eq!(tab.class_name(), "active");
+/// ```
+pub fn toggle_class_if(el: &Element, class: &str, condition: bool) {
+    el.class_list().toggle_with_force(class, condition).unwrap();
+}
+
+/// Add several classes to an element
+///
+/// This function calls javascript's [`element.classList.add()`](https://developer.mozilla.org/en-US/docs/Web/API/DOMTokenList/add) method for every class in `classes`.
+/// Classes which the element already has are not duplicated. An empty slice does nothing.
+///
+///
+/// # Panics
+///
+/// * This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+/// * This function will panic if one of the `classes` is empty or contains whitespace
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use webru::{add_classes, get_element_by_id, remove_classes};
+///
+/// let button = get_element_by_id("submit").unwrap();
+///
+/// // while the request is pending
+/// add_classes(&button, &["loading", "disabled"]);
+///
+/// // when it's done
+/// remove_classes(&button, &["loading", "disabled"]);
+/// ```
+pub fn add_classes(el: &Element, classes: &[&str]) {
+    let class_list = el.class_list();
+
+    for class in classes {
+        class_list.add_1(class).unwrap();
+    }
+}
+
+/// Remove several classes from an element
+///
+/// This function calls javascript's [`element.classList.remove()`](https://developer.mozilla.org/en-US/docs/Web/API/DOMTokenList/remove) method for every class in `classes`.
+/// Classes which the element doesn't have are ignored. An empty slice does nothing.
+///
+///
+/// # Panics
+///
+/// * This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+/// * This function will panic if one of the `classes` is empty or contains whitespace
+///
+pub fn remove_classes(el: &Element, classes: &[&str]) {
+    let class_list = el.class_list();
+
+    for class in classes {
+        class_list.remove_1(class).unwrap();
+    }
+}
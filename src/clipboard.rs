@@ -0,0 +1,96 @@
+use js_sys::{Function, Promise, Reflect};
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{window, HtmlDocument, HtmlTextAreaElement};
+
+use crate::{body, create_element, document};
+
+/// Copy a text to the clipboard, even on insecure (`http://`) pages
+///
+/// This function uses the [`navigator.clipboard.writeText()`](https://developer.mozilla.org/en-US/docs/Web/API/Clipboard/writeText) method when it's available.
+/// The Clipboard API only exists in [secure contexts](https://developer.mozilla.org/en-US/docs/Web/Security/Secure_Contexts) (`https://` and `localhost`),
+/// so on other pages (or if the Clipboard API rejects the request) this function falls back to creating a hidden `<textarea>`, selecting its content and calling `document.execCommand("copy")`.
+///
+/// Note that [`document.execCommand()`](https://developer.mozilla.org/en-US/docs/Web/API/Document/execCommand) is deprecated.
+/// Browsers still support it for copying, but it may be removed in the future, and it usually only works inside a user gesture such as a click handler.
+///
+/// It returns `Err` if neither way could copy the text
+///
+///
+/// # Arguments
+///
+/// * `text` - The text to copy
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use webru::copy_to_clipboard_compat;
+/// use weblog::console_log;
+///
+/// // call it inside a click handler
+/// wasm_bindgen_futures::spawn_local(async {
+///     match copy_to_clipboard_compat("Hello world").await {
+///         Ok(()) => console_log!("Copied!"),
+///         Err(_) => console_log!("Couldn't copy"),
+///     }
+/// });
+/// ```
+pub async fn copy_to_clipboard_compat(text: &str) -> Result<(), JsValue> {
+    if write_text(text).await.is_ok() {
+        return Ok(());
+    }
+
+    copy_with_exec_command(text)
+}
+
+/// Copy with `navigator.clipboard.writeText()`. `navigator.clipboard` is `undefined` in insecure contexts.
+async fn write_text(text: &str) -> Result<(), JsValue> {
+    let clipboard = Reflect::get(&window().unwrap().navigator(), &"clipboard".into())?;
+
+    if clipboard.is_undefined() || clipboard.is_null() {
+        return Err(JsValue::from_str("the Clipboard API is not available"));
+    }
+
+    let promise = Reflect::get(&clipboard, &"writeText".into())?
+        .dyn_into::<Function>()?
+        .call1(&clipboard, &text.into())?
+        .dyn_into::<Promise>()?;
+
+    JsFuture::from(promise).await?;
+
+    Ok(())
+}
+
+/// Copy with a hidden `<textarea>` and the deprecated `document.execCommand("copy")`
+fn copy_with_exec_command(text: &str) -> Result<(), JsValue> {
+    let textarea = create_element("textarea").dyn_into::<HtmlTextAreaElement>()?;
+
+    textarea.set_value(text);
+    textarea.set_attribute("readonly", "")?;
+
+    // keep it out of sight without using `display: none`, which would make it unselectable
+    let style = textarea.style();
+    style.set_property("position", "fixed")?;
+    style.set_property("top", "0")?;
+    style.set_property("left", "0")?;
+    style.set_property("opacity", "0")?;
+
+    body().append_child(&textarea)?;
+    textarea.select();
+
+    let copied = document().dyn_into::<HtmlDocument>()?.exec_command("copy");
+
+    textarea.remove();
+
+    if copied? {
+        Ok(())
+    } else {
+        Err(JsValue::from_str("document.execCommand(\"copy\") failed"))
+    }
+}
@@ -0,0 +1,88 @@
+use js_sys::{decode_uri_component, encode_uri_component};
+use wasm_bindgen::JsCast;
+use web_sys::HtmlDocument;
+
+use crate::document;
+
+/// Set a cookie
+///
+/// This function builds the string for javascript's [`document.cookie`](https://developer.mozilla.org/en-US/docs/Web/API/Document/cookie) property and assigns it.
+/// The `value` is URL-encoded, so it can contain any character, including `;` and `=`. The cookie is set for the path `/`, so every page of the site can read it.
+///
+///
+/// # Arguments
+///
+/// * `name` - The name of the cookie
+///
+/// * `value` - The value of the cookie
+///
+/// * `max_age_secs` - How many seconds the cookie lives. `None` creates a session cookie, which is removed when the browser is closed. `Some(0)` removes the cookie
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use webru::{get_cookie, set_cookie};
+///
+/// // remember the language for 30 days
+/// set_cookie("lang", "en", Some(30 * 24 * 60 * 60));
+///
+/// assert_eq!(get_cookie("lang"), Some("en".to_string()));
+///
+/// // remove the cookie
+/// set_cookie("lang", "", Some(0));
+/// ```
+pub fn set_cookie(name: &str, value: &str, max_age_secs: Option<i32>) {
+    let mut cookie = format!("{}={}; path=/", name, encode_uri_component(value));
+
+    if let Some(max_age_secs) = max_age_secs {
+        cookie.push_str(&format!("; max-age={}", max_age_secs));
+    }
+
+    html_document().set_cookie(&cookie).unwrap();
+}
+
+/// Get the value of a cookie
+///
+/// This function parses javascript's [`document.cookie`](https://developer.mozilla.org/en-US/docs/Web/API/Document/cookie) string and returns the URL-decoded value of the cookie named `name`.
+///
+/// It returns `None` if there is no such cookie. Cookies marked as `HttpOnly` by the server can't be read from javascript, so they are `None` too.
+///
+///
+/// # Arguments
+///
+/// * `name` - The name of the cookie
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+pub fn get_cookie(name: &str) -> Option<String> {
+    let cookies = html_document().cookie().unwrap();
+
+    cookies.split(';').find_map(|cookie| {
+        // values can contain `=`, so only split on the first one
+        let (key, value) = cookie.trim_start().split_once('=')?;
+
+        if key != name {
+            return None;
+        }
+
+        // keep the raw value if it isn't valid URL encoding
+        Some(
+            decode_uri_component(value)
+                .map(String::from)
+                .unwrap_or_else(|_| value.to_string()),
+        )
+    })
+}
+
+fn html_document() -> HtmlDocument {
+    document().dyn_into::<HtmlDocument>().unwrap()
+}
@@ -0,0 +1,51 @@
+use js_sys::Reflect;
+use wasm_bindgen::JsValue;
+use web_sys::window;
+
+/// The maximum number of bytes `getRandomValues()` fills in one call
+const MAX_RANDOM_BYTES: usize = 65536;
+
+/// Generate cryptographically secure random bytes
+///
+/// This function fills a buffer with javascript's [`crypto.getRandomValues()`](https://developer.mozilla.org/en-US/docs/Web/API/Crypto/getRandomValues) method of the Web Crypto API.
+/// Unlike `Math.random()`, the bytes are suitable for tokens, nonces and keys.
+///
+/// The Web Crypto API is meant for [secure contexts](https://developer.mozilla.org/en-US/docs/Web/Security/Secure_Contexts) (`https://` and `localhost`).
+/// If `window.crypto` is not available, it returns `Err` instead of panicking.
+///
+/// `getRandomValues()` only fills 65536 bytes at a time, so larger buffers are filled in several calls.
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use webru::random_bytes;
+///
+/// let nonce = random_bytes(16).unwrap();
+///
+/// let hex: String = nonce.iter().map(|byte| format!("{:02x}", byte)).collect();
+/// ```
+pub fn random_bytes(len: usize) -> Result<Vec<u8>, JsValue> {
+    let window = window().unwrap();
+
+    let crypto = Reflect::get(&window, &"crypto".into())?;
+
+    if crypto.is_undefined() || crypto.is_null() {
+        return Err(JsValue::from_str("the Web Crypto API is not available"));
+    }
+
+    let crypto = window.crypto()?;
+
+    let mut bytes = vec![0; len];
+
+    for chunk in bytes.chunks_mut(MAX_RANDOM_BYTES) {
+        crypto.get_random_values_with_u8_array(chunk)?;
+    }
+
+    Ok(bytes)
+}
@@ -0,0 +1,627 @@
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{DocumentFragment, Element, HtmlTemplateElement, Node};
+
+use crate::{document, html_collection_iter, node_list_iter, query_selector_all};
+
+/// Insert a node at a specific position among an element's children
+///
+/// The `child` is inserted so that it ends up at `index` among the element children of the `parent`. If `index` is greater than or equal to the number of other children, the `child` is appended at the end.
+///
+/// Only element children are counted, so text and comment nodes (like the whitespace between tags) don't shift the index.
+///
+/// If the `child` is already in the document, it's moved to the new position. This makes the function useful for reordering lists, for example after a drag and drop.
+/// The `index` is the final position: moving the first of three children to index `2` makes it the last child.
+///
+///
+/// # Arguments
+///
+/// * `parent` - The element to insert into
+///
+/// * `child` - The node to insert
+///
+/// * `index` - The position of the `child` among the element children of the `parent` after the insertion
+///
+///
+/// # Panics
+///
+/// * This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+/// * This function will panic if the `child` can't be inserted into the `parent`, for example if the `child` is an ancestor of the `parent`
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use webru::{create_element, insert_at};
+///
+/// let list = create_element("ul");
+/// list.set_inner_html("<li>a</li><li>c</li>");
+///
+/// let b = create_element("li");
+/// b.set_inner_html("b");
+///
+/// insert_at(&list, &b, 1);
+///
+/// assert_eq!(list.inner_html(), "<li>a</li><li>b</li><li>c</li>");
+/// ```
+pub fn insert_at(parent: &Element, child: &Node, index: usize) {
+    // the `child` itself is skipped, as it's moved away from its current position
+    let reference = html_collection_iter(parent.children())
+        .filter(|element| AsRef::<Node>::as_ref(element) != child)
+        .nth(index);
+
+    parent
+        .insert_before(child, reference.as_ref().map(|element| element.as_ref()))
+        .unwrap();
+}
+
+/// Append several nodes to a parent at once
+///
+/// The `children` are first collected into a [`DocumentFragment`](https://developer.mozilla.org/en-US/docs/Web/API/DocumentFragment), which is then appended to the `parent` with a single `appendChild()` call.
+/// So the live DOM is modified only once, instead of once per child, which makes mounting many nodes faster.
+///
+/// The children are appended in the order of the slice. Children which are already in the document are moved.
+///
+/// It returns `Err` if one of the `children` is the `parent` itself or one of its ancestors. This is checked before any node is moved, so the document is not modified in that case.
+/// Otherwise, it returns the first `Err` thrown by `appendChild()`.
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use webru::{append_children, body, create_element};
+///
+/// let h1 = create_element("h1");
+/// let p = create_element("p");
+/// let button = create_element("button");
+///
+/// append_children(&body(), &[&h1, &p, &button]).unwrap();
+/// ```
+pub fn append_children(parent: &Node, children: &[&Node]) -> Result<(), JsValue> {
+    // moving an ancestor into the fragment would detach the `parent` before the final `appendChild()` fails
+    if children.iter().any(|child| child.contains(Some(parent))) {
+        return Err(JsValue::from_str(
+            "a child is the parent itself or one of its ancestors",
+        ));
+    }
+
+    let fragment = document().create_document_fragment();
+
+    for child in children {
+        fragment.append_child(child)?;
+    }
+
+    parent.append_child(&fragment)?;
+
+    Ok(())
+}
+
+/// Get the sibling elements of an element
+///
+/// This function returns the element children of the element's parent, except the element itself. They are sorted as they appear in the document.
+///
+/// Text and comment nodes are not included. If the element has no parent, it returns an empty `Vec`.
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use webru::{get_element_by_id, siblings};
+///
+/// let active_tab = get_element_by_id("active-tab").unwrap();
+///
+/// for tab in siblings(&active_tab) {
+///     tab.class_list().remove_1("active").unwrap();
+/// }
+/// ```
+pub fn siblings(el: &Element) -> Vec<Element> {
+    let parent = match el.parent_element() {
+        Some(parent) => parent,
+        None => return Vec::new(),
+    };
+
+    let children = parent.children();
+
+    (0..children.length())
+        .filter_map(|i| children.item(i))
+        .filter(|child| child != el)
+        .collect()
+}
+
+/// Get the parent element of an element
+///
+/// This function is equivalent to javascript's [`element.parentElement`](https://developer.mozilla.org/en-US/docs/Web/API/Node/parentElement) property.
+///
+/// It returns `None` if the element is detached (not inserted anywhere), if it's the `<html>` element, or if its parent is not an element, like a `DocumentFragment`.
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use webru::{get_element_by_id, parent};
+///
+/// let delete_button = get_element_by_id("delete-row-3").unwrap();
+///
+/// // remove the whole row
+/// if let Some(row) = parent(&delete_button) {
+///     row.remove();
+/// }
+/// ```
+pub fn parent(el: &Element) -> Option<Element> {
+    el.parent_element()
+}
+
+/// Get the next sibling element of an element
+///
+/// This function is equivalent to javascript's [`element.nextElementSibling`](https://developer.mozilla.org/en-US/docs/Web/API/Element/nextElementSibling) property. Text and comment nodes are skipped.
+///
+/// It returns `None` if the element is the last child element of its parent or if it has no parent.
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+pub fn next_sibling_element(el: &Element) -> Option<Element> {
+    el.next_element_sibling()
+}
+
+/// Get the previous sibling element of an element
+///
+/// This function is equivalent to javascript's [`element.previousElementSibling`](https://developer.mozilla.org/en-US/docs/Web/API/Element/previousElementSibling) property. Text and comment nodes are skipped.
+///
+/// It returns `None` if the element is the first child element of its parent or if it has no parent.
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+pub fn prev_sibling_element(el: &Element) -> Option<Element> {
+    el.previous_element_sibling()
+}
+
+/// Visit every descendant element of an element
+///
+/// The `visitor` is called on each element inside `root` (but not on `root` itself) in depth-first order, which is the order they appear in the HTML.
+/// Text and comment nodes are skipped.
+///
+/// The traversal doesn't use recursion, so it works for deeply nested trees too. Don't add or remove elements inside the `visitor`, as it can make the traversal skip elements.
+///
+///
+/// # Arguments
+///
+/// * `root` - The element whose descendants are visited
+///
+/// * `visitor` - The Rust closure called with every element
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use weblog::console_log;
+/// use webru::{body, walk_elements};
+///
+/// // find the images without an alt text
+/// walk_elements(&body(), |el| {
+///     if el.tag_name() == "IMG" && !el.has_attribute("alt") {
+///         console_log!("Missing alt text: ", el.outer_html());
+///     }
+/// });
+/// ```
+pub fn walk_elements<T>(root: &Element, mut visitor: T)
+where
+    T: FnMut(&Element),
+{
+    let mut current = root.first_element_child();
+
+    while let Some(el) = current {
+        visitor(&el);
+
+        current = next_element(&el, root);
+    }
+}
+
+/// Find the first descendant element matching a condition
+///
+/// This function searches the elements inside `root` (but not `root` itself) in depth-first order, like [`walk_elements`], and returns the first one for which `predicate` returns `true`.
+/// The search stops at the first match.
+///
+/// It's useful when a CSS selector can't express the condition, for example when comparing the text of the elements. Otherwise prefer `root.query_selector()`, which is faster.
+///
+///
+/// # Arguments
+///
+/// * `root` - The element to search in
+///
+/// * `predicate` - The Rust closure deciding if an element matches
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use webru::{body, find_element};
+///
+/// let button = find_element(&body(), |el| {
+///     el.tag_name() == "BUTTON" && el.text_content().unwrap_or_default().trim() == "Save"
+/// });
+/// ```
+pub fn find_element<T>(root: &Element, predicate: T) -> Option<Element>
+where
+    T: Fn(&Element) -> bool,
+{
+    let mut current = root.first_element_child();
+
+    while let Some(el) = current {
+        if predicate(&el) {
+            return Some(el);
+        }
+
+        current = next_element(&el, root);
+    }
+
+    None
+}
+
+/// The element after `el` in depth-first order, without leaving `root`
+fn next_element(el: &Element, root: &Element) -> Option<Element> {
+    if let Some(child) = el.first_element_child() {
+        return Some(child);
+    }
+
+    let mut el = el.clone();
+
+    loop {
+        if let Some(sibling) = el.next_element_sibling() {
+            return Some(sibling);
+        }
+
+        el = el.parent_element()?;
+
+        if &el == root {
+            return None;
+        }
+    }
+}
+
+/// Remove an element from the DOM
+///
+/// This function is equivalent to javascript's [`element.remove()`](https://developer.mozilla.org/en-US/docs/Web/API/Element/remove) method.
+///
+/// If the element is not in the DOM, this function does nothing.
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use webru::{get_element_by_id, remove_element};
+///
+/// if let Some(banner) = get_element_by_id("cookie-banner") {
+///     remove_element(&banner);
+/// }
+/// ```
+pub fn remove_element(el: &Element) {
+    el.remove();
+}
+
+/// Replace an element with another element
+///
+/// This function is equivalent to javascript's [`element.replaceWith(new)`](https://developer.mozilla.org/en-US/docs/Web/API/Element/replaceWith) method.
+/// The `new` element takes the place of the `old` one in its parent. If the `new` element is already in the document, it's moved.
+///
+/// If the `old` element has no parent, this function does nothing. It returns the `Err` thrown by `replaceWith()`, for example when the `new` element is an ancestor of the `old` one.
+///
+///
+/// # Arguments
+///
+/// * `old` - The element to replace
+///
+/// * `new` - The element to insert instead
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use webru::{create_element, get_element_by_id, replace_element};
+///
+/// let spinner = get_element_by_id("spinner").unwrap();
+///
+/// let content = create_element("p");
+/// content.set_inner_html("Loaded!");
+///
+/// replace_element(&spinner, &content).unwrap();
+/// ```
+pub fn replace_element(old: &Element, new: &Element) -> Result<(), JsValue> {
+    old.replace_with_with_node_1(new)
+}
+
+/// Set the HTML content of an element
+///
+/// This function is equivalent to setting javascript's [`element.innerHTML`](https://developer.mozilla.org/en-US/docs/Web/API/Element/innerHTML) property. The current children of the element are replaced by the parsed `html`.
+///
+/// **Never pass untrusted input** (like user input or data from an API) to this function. The string is parsed as HTML,
+/// so it can inject elements with event handlers like `<img src=x onerror="...">` and run arbitrary javascript (XSS).
+/// Use [`set_text`] to display untrusted text.
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use webru::{get_html, query_selector, set_html};
+///
+/// let list = query_selector("ul.todos").unwrap();
+///
+/// set_html(&list, "<li>Write docs</li><li>Release</li>");
+///
+/// assert_eq!(get_html(&list), "<li>Write docs</li><li>Release</li>");
+/// ```
+pub fn set_html(el: &Element, html: &str) {
+    el.set_inner_html(html);
+}
+
+/// Get the HTML content of an element
+///
+/// This function returns javascript's [`element.innerHTML`](https://developer.mozilla.org/en-US/docs/Web/API/Element/innerHTML) property, which is the serialized HTML of the element's children.
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+pub fn get_html(el: &Element) -> String {
+    el.inner_html()
+}
+
+/// Set the text content of an element
+///
+/// This function is equivalent to setting javascript's [`node.textContent`](https://developer.mozilla.org/en-US/docs/Web/API/Node/textContent) property. The current children of the element are replaced by a single text node.
+///
+/// The `text` is never parsed as HTML, so this is the safe way to display untrusted input.
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+pub fn set_text(el: &Element, text: &str) {
+    el.set_text_content(Some(text));
+}
+
+/// Parse an HTML string into detached nodes
+///
+/// The `html` is parsed by a [`<template>`](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/template) element and its [`content`](https://developer.mozilla.org/en-US/docs/Web/API/HTMLTemplateElement/content) is returned.
+/// The nodes are not inserted into the document, so you can inspect or modify them before appending the fragment somewhere.
+///
+/// Unlike parsing with a `<div>`, a template can hold any element, so fragments like `<tr>` or `<td>` are kept as they are.
+///
+/// Scripts in the parsed fragment don't execute, not even after the fragment is inserted into the document.
+/// Event handler attributes like `onerror` do run once the nodes are inserted, so don't parse untrusted input.
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use webru::{parse_html, query_selector};
+///
+/// let rows = parse_html("<tr><td>1</td></tr><tr><td>2</td></tr>");
+///
+/// query_selector("tbody").unwrap().append_child(&rows).unwrap();
+/// ```
+pub fn parse_html(html: &str) -> DocumentFragment {
+    let template = document()
+        .create_element("template")
+        .unwrap()
+        .dyn_into::<HtmlTemplateElement>()
+        .unwrap();
+
+    template.set_inner_html(html);
+
+    template.content()
+}
+
+/// Clone the content of a `<template>` element
+///
+/// This function deep-clones the template's [`content`](https://developer.mozilla.org/en-US/docs/Web/API/HTMLTemplateElement/content) and returns a fresh fragment, ready to be filled and appended.
+/// It's the idiomatic way to render lists: define the markup of an item once in the HTML, then clone it for every item.
+///
+/// Every call copies the whole subtree of the template, so keep templates small when rendering long lists.
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use wasm_bindgen::JsCast;
+/// use web_sys::HtmlTemplateElement;
+/// use webru::{clone_template, get_element_by_id};
+///
+/// // <template id="todo"><li class="todo"></li></template>
+/// let template: HtmlTemplateElement = get_element_by_id("todo").unwrap().dyn_into().unwrap();
+/// let list = get_element_by_id("todos").unwrap();
+///
+/// for todo in ["Write docs", "Release"] {
+///     let item = clone_template(&template);
+///     item.first_element_child().unwrap().set_text_content(Some(todo));
+///
+///     list.append_child(&item).unwrap();
+/// }
+/// ```
+pub fn clone_template(template: &HtmlTemplateElement) -> DocumentFragment {
+    template
+        .content()
+        .clone_node_with_deep(true)
+        .unwrap()
+        .dyn_into::<DocumentFragment>()
+        .unwrap()
+}
+
+/// Change the icon of the page
+///
+/// This function sets the `href` of the `<link rel="icon">` elements of the document, which is useful for notification badges or dark mode icons.
+///
+/// If the document has several icon links (for example different sizes, or a legacy `rel="shortcut icon"` link), all of them are updated, so the browser shows the new icon no matter which one it picks.
+/// If there is no icon link, one is created and appended to [`document().head()`](https://developer.mozilla.org/en-US/docs/Web/API/Document/head).
+///
+///
+/// # Panics
+///
+/// * This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+/// * This function will panic if the document has no `<head>`
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use webru::set_favicon;
+///
+/// // when a new message arrives
+/// set_favicon("/favicon-unread.png");
+/// ```
+pub fn set_favicon(href: &str) {
+    let links: Vec<Node> = node_list_iter(query_selector_all("link[rel~=\"icon\"]")).collect();
+
+    if links.is_empty() {
+        let link = document().create_element("link").unwrap();
+        link.set_attribute("rel", "icon").unwrap();
+        link.set_attribute("href", href).unwrap();
+
+        document().head().unwrap().append_child(&link).unwrap();
+        return;
+    }
+
+    for link in links {
+        link.dyn_into::<Element>()
+            .unwrap()
+            .set_attribute("href", href)
+            .unwrap();
+    }
+}
+
+/// The position passed to [`insert_adjacent_html`]
+///
+/// The positions are relative to the element:
+///
+/// ```text
+/// <!-- BeforeBegin -->
+/// <p>
+///     <!-- AfterBegin -->
+///     content
+///     <!-- BeforeEnd -->
+/// </p>
+/// <!-- AfterEnd -->
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AdjacentPosition {
+    /// Before the element itself
+    BeforeBegin,
+    /// Inside the element, before its first child
+    AfterBegin,
+    /// Inside the element, after its last child
+    BeforeEnd,
+    /// After the element itself
+    AfterEnd,
+}
+
+impl AdjacentPosition {
+    /// The name of the position used by javascript
+    fn as_str(self) -> &'static str {
+        match self {
+            AdjacentPosition::BeforeBegin => "beforebegin",
+            AdjacentPosition::AfterBegin => "afterbegin",
+            AdjacentPosition::BeforeEnd => "beforeend",
+            AdjacentPosition::AfterEnd => "afterend",
+        }
+    }
+}
+
+/// Parse HTML and insert the nodes at a position relative to an element
+///
+/// This function is equivalent to javascript's [`element.insertAdjacentHTML(position, html)`](https://developer.mozilla.org/en-US/docs/Web/API/Element/insertAdjacentHTML) method.
+/// Unlike [`set_html`], the existing children of the element are kept, and they are not parsed again.
+///
+/// **Never pass untrusted input** to this function. Just like [`set_html`], the `html` can inject elements which run arbitrary javascript (XSS).
+///
+/// It returns `Err` if the position is outside of the element (`BeforeBegin` or `AfterEnd`) and the element has no parent element.
+///
+///
+/// # Arguments
+///
+/// * `el` - The element the position is relative to
+///
+/// * `position` - Where to insert the nodes. See [`AdjacentPosition`]
+///
+/// * `html` - The HTML to parse
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use webru::{get_element_by_id, insert_adjacent_html, AdjacentPosition};
+///
+/// let messages = get_element_by_id("messages").unwrap();
+///
+/// // append a new message without re-rendering the old ones
+/// insert_adjacent_html(&messages, AdjacentPosition::BeforeEnd, "<li>New message</li>").unwrap();
+/// ```
+pub fn insert_adjacent_html(
+    el: &Element,
+    position: AdjacentPosition,
+    html: &str,
+) -> Result<(), JsValue> {
+    el.insert_adjacent_html(position.as_str(), html)
+}
@@ -0,0 +1,69 @@
+use js_sys::{Array, Uint8Array};
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{Blob, BlobPropertyBag, HtmlAnchorElement, Url};
+
+use crate::{body, create_element, set_timeout};
+
+/// Let the user download data generated by the page as a file
+///
+/// This function puts the `data` into a [`Blob`](https://developer.mozilla.org/en-US/docs/Web/API/Blob), creates an object URL for it and clicks a temporary `<a download>` element.
+/// The anchor is removed right after the click, and the object URL is revoked once the download started, so nothing is leaked.
+///
+/// Note that the browser decides the final name of the file: characters which are not allowed in file names (like `/` or `:`) are replaced,
+/// a number may be appended if the file already exists, and the user may be asked where to save it. Some browsers only allow downloads inside a user gesture.
+///
+/// It returns `Err` if the `Blob` or the object URL couldn't be created.
+///
+///
+/// # Arguments
+///
+/// * `data` - The content of the file
+///
+/// * `filename` - The suggested name of the file
+///
+/// * `mime` - The [MIME type](https://developer.mozilla.org/en-US/docs/Web/HTTP/Basics_of_HTTP/MIME_types) of the file, like `text/csv` or `application/json`
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use webru::download_blob;
+///
+/// let csv = "name,age\nShanto,17\n";
+///
+/// download_blob(csv.as_bytes(), "people.csv", "text/csv").unwrap();
+/// ```
+pub fn download_blob(data: &[u8], filename: &str, mime: &str) -> Result<(), JsValue> {
+    let options = BlobPropertyBag::new();
+    options.set_type(mime);
+
+    let parts = Array::of1(&Uint8Array::from(data));
+    let blob = Blob::new_with_u8_array_sequence_and_options(&parts, &options)?;
+
+    let url = Url::create_object_url_with_blob(&blob)?;
+
+    let anchor = create_element("a").dyn_into::<HtmlAnchorElement>()?;
+    anchor.set_href(&url);
+    anchor.set_download(filename);
+    anchor.style().set_property("display", "none")?;
+
+    // Firefox ignores clicks on anchors which are not in the document
+    body().append_child(&anchor)?;
+    anchor.click();
+    anchor.remove();
+
+    // revoking right away can cancel the download in some browsers
+    set_timeout(
+        move || {
+            let _ = Url::revoke_object_url(&url);
+        },
+        0,
+    )?;
+
+    Ok(())
+}
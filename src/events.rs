@@ -0,0 +1,569 @@
+use js_sys::Promise;
+use wasm_bindgen::closure::{Closure, WasmClosure};
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{
+    window, BeforeUnloadEvent, Element, Event, EventTarget, HtmlElement, HtmlFormElement,
+    HtmlInputElement, KeyboardEvent,
+};
+
+use crate::clear_timeout;
+use crate::timer::start_timeout;
+
+use std::mem::ManuallyDrop;
+
+/// A handle to an event listener registered with javascript's [`addEventListener()`](https://developer.mozilla.org/en-US/docs/Web/API/EventTarget/addEventListener) method
+///
+/// The handle owns the Rust closure of the listener. When the handle is dropped (or when [`remove`](EventListenerHandle::remove) is called), the listener is removed with [`removeEventListener()`](https://developer.mozilla.org/en-US/docs/Web/API/EventTarget/removeEventListener) and the closure is freed.
+///
+/// So unlike the [`callback`](crate::callback) function, you don't need to call `.forget()` and leak the closure. Just keep the handle alive as long as you want to listen to the event.
+pub struct EventListenerHandle {
+    listeners: Vec<Listener>,
+}
+
+/// One registered listener of an [`EventListenerHandle`]
+struct Listener {
+    target: EventTarget,
+    event: String,
+    closure: Closure<dyn FnMut(Event)>,
+}
+
+impl EventListenerHandle {
+    /// Removes the event listener.
+    ///
+    /// This is the same as dropping the handle.
+    ///
+    pub fn remove(self) {
+        drop(self);
+    }
+
+    /// Keeps the event listener registered for the lifetime of the page.
+    ///
+    /// The Rust closure is leaked, just like calling `.forget()` on a [`Closure`].
+    ///
+    pub fn forget(mut self) {
+        for listener in self.listeners.drain(..) {
+            listener.closure.forget();
+        }
+    }
+
+    /// Combine two handles, so the listeners of both are removed together
+    pub(crate) fn join(mut self, mut other: EventListenerHandle) -> Self {
+        self.listeners.append(&mut other.listeners);
+        self
+    }
+}
+
+impl Drop for EventListenerHandle {
+    fn drop(&mut self) {
+        for listener in self.listeners.drain(..) {
+            listener
+                .target
+                .remove_event_listener_with_callback(
+                    &listener.event,
+                    listener.closure.as_ref().unchecked_ref(),
+                )
+                .unwrap();
+        }
+    }
+}
+
+/// Javascript [`addEventListener()`](https://developer.mozilla.org/en-US/docs/Web/API/EventTarget/addEventListener) method
+///
+/// This function registers the `handler` to be called whenever `event` is delivered to the `target`, which can be an element, the document or the window.
+///
+/// Unlike setting properties like `onclick` with a [`callback`](crate::callback), you don't need to call `.forget()`:
+/// the listener is removed and the closure is freed when the returned [`EventListenerHandle`] is dropped (or when its `remove` method is called).
+/// Call `forget` on the handle to keep the listener for the lifetime of the page.
+///
+///
+/// # Arguments
+///
+/// * `target` - The [`EventTarget`](https://developer.mozilla.org/en-US/docs/Web/API/EventTarget) to listen on
+///
+/// * `event` - The name of the event, like `click` or `keydown`
+///
+/// * `handler` - The Rust closure receiving the event
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use weblog::console_log;
+/// use webru::{add_event_listener, get_element_by_id};
+///
+/// let button = get_element_by_id("save").unwrap();
+///
+/// let listener = add_event_listener(&button, "click", |event| {
+///     console_log!("Clicked: ", event.type_());
+/// });
+///
+/// // later, stop listening
+/// listener.remove();
+/// ```
+pub fn add_event_listener<T>(target: &EventTarget, event: &str, handler: T) -> EventListenerHandle
+where
+    T: Fn(Event) + 'static,
+{
+    listen(target, event, handler)
+}
+
+/// Register `handler` on `target` for `event` and return the handle which removes it on drop
+pub(crate) fn listen<T>(target: &EventTarget, event: &str, handler: T) -> EventListenerHandle
+where
+    T: FnMut(Event) + 'static,
+{
+    let closure = Closure::wrap(Box::new(handler) as Box<dyn FnMut(Event)>);
+
+    target
+        .add_event_listener_with_callback(event, closure.as_ref().unchecked_ref())
+        .unwrap();
+
+    EventListenerHandle {
+        listeners: vec![Listener {
+            target: target.clone(),
+            event: event.to_string(),
+            closure,
+        }],
+    }
+}
+
+/// Listen to the [`animationend`](https://developer.mozilla.org/en-US/docs/Web/API/Element/animationend_event) event of an element
+///
+/// The `handler` is called every time a CSS animation of the element (or of one of its children, as the event bubbles) completes.
+/// The listener is removed when the returned [`EventListenerHandle`] is dropped.
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use webru::{get_element_by_id, on_animation_end};
+///
+/// let banner = get_element_by_id("banner").unwrap();
+///
+/// let listener = on_animation_end(&banner, {
+///     let banner = banner.clone();
+///     move || banner.class_list().remove_1("fade-in").unwrap()
+/// });
+///
+/// banner.class_list().add_1("fade-in").unwrap();
+///
+/// // keep listening for the lifetime of the page
+/// listener.forget();
+/// ```
+pub fn on_animation_end<T>(el: &Element, mut handler: T) -> EventListenerHandle
+where
+    T: FnMut() + 'static,
+{
+    listen(el, "animationend", move |_| handler())
+}
+
+/// Listen to the [`transitionend`](https://developer.mozilla.org/en-US/docs/Web/API/Element/transitionend_event) event of an element
+///
+/// The `handler` is called every time a CSS transition of the element (or of one of its children, as the event bubbles) completes.
+/// Note that a transition of several properties fires one event per property.
+///
+/// The listener is removed when the returned [`EventListenerHandle`] is dropped.
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+pub fn on_transition_end<T>(el: &Element, mut handler: T) -> EventListenerHandle
+where
+    T: FnMut() + 'static,
+{
+    listen(el, "transitionend", move |_| handler())
+}
+
+/// Wait for the next [`transitionend`](https://developer.mozilla.org/en-US/docs/Web/API/Element/transitionend_event) event of an element
+///
+/// This is the `async` version of [`on_transition_end`]. It lets you write sequential animation code with `.await` instead of nesting callbacks.
+///
+/// If the transition never happens (for example because the property didn't change or the element is hidden), the future would never resolve.
+/// To guard against that, pass a `timeout_ms` after which the future resolves anyway.
+///
+///
+/// # Arguments
+///
+/// * `el` - The element to wait for
+///
+/// * `timeout_ms` - Resolve after this many milliseconds even if no `transitionend` event fired. `None` waits forever
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use webru::{get_element_by_id, wait_for_transition};
+///
+/// wasm_bindgen_futures::spawn_local(async {
+///     let menu = get_element_by_id("menu").unwrap();
+///
+///     menu.class_list().add_1("slide-out").unwrap();
+///     wait_for_transition(&menu, Some(1000)).await;
+///
+///     // the menu is not visible anymore
+///     menu.remove();
+/// });
+/// ```
+pub async fn wait_for_transition(el: &Element, timeout_ms: Option<i32>) {
+    let mut listener = None;
+    let mut timeout_id = None;
+
+    let promise = Promise::new(&mut |resolve, _reject| {
+        listener = Some(listen(el, "transitionend", {
+            let resolve = resolve.clone();
+            move |_| {
+                resolve.call0(&JsValue::UNDEFINED).unwrap();
+            }
+        }));
+
+        if let Some(timeout_ms) = timeout_ms {
+            timeout_id = Some(start_timeout(&resolve, timeout_ms).unwrap());
+        }
+    });
+
+    JsFuture::from(promise).await.unwrap();
+
+    drop(listener);
+
+    if let Some(timeout_id) = timeout_id {
+        clear_timeout(timeout_id);
+    }
+}
+
+/// Ask the user to confirm before leaving the page
+///
+/// The `handler` is called on the window's [`beforeunload`](https://developer.mozilla.org/en-US/docs/Web/API/Window/beforeunload_event) event.
+/// If it returns `true`, the browser shows its native "Leave site?" dialog. This is useful to warn about unsaved changes.
+///
+/// Browsers don't show custom messages in this dialog anymore, and they may only show it if the user has interacted with the page.
+///
+/// The listener is removed when the returned [`EventListenerHandle`] is dropped.
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use std::cell::Cell;
+/// use std::rc::Rc;
+/// use webru::on_before_unload;
+///
+/// let has_unsaved_changes = Rc::new(Cell::new(false));
+///
+/// let guard = on_before_unload({
+///     let has_unsaved_changes = Rc::clone(&has_unsaved_changes);
+///     move || has_unsaved_changes.get()
+/// });
+///
+/// // when the user edits something
+/// has_unsaved_changes.set(true);
+/// ```
+pub fn on_before_unload<T>(mut handler: T) -> EventListenerHandle
+where
+    T: FnMut() -> bool + 'static,
+{
+    listen(&window().unwrap(), "beforeunload", move |event| {
+        if handler() {
+            event.prevent_default();
+
+            // older browsers only show the dialog if `returnValue` is set
+            if let Some(event) = event.dyn_ref::<BeforeUnloadEvent>() {
+                event.set_return_value("");
+            }
+        }
+    })
+}
+
+/// Javascript [`event.preventDefault()`](https://developer.mozilla.org/en-US/docs/Web/API/Event/preventDefault) method
+///
+/// This function tells the browser not to run the default action of the event, for example following a link on `click` or sending a form on `submit`.
+/// The event keeps propagating to the other listeners.
+///
+/// It does nothing if the event is not cancelable.
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use webru::{add_event_listener, get_element_by_id, prevent_default};
+///
+/// let form = get_element_by_id("signup").unwrap();
+///
+/// add_event_listener(&form, "submit", |event| {
+///     // don't reload the page
+///     prevent_default(&event);
+/// })
+/// .forget();
+/// ```
+pub fn prevent_default(e: &Event) {
+    e.prevent_default();
+}
+
+/// Javascript [`event.stopPropagation()`](https://developer.mozilla.org/en-US/docs/Web/API/Event/stopPropagation) method
+///
+/// This function stops the event from reaching the listeners of the parent elements. The other listeners of the current element are still called.
+///
+/// The default action of the event is not prevented. Use [`prevent_default`] for that.
+///
+pub fn stop_propagation(e: &Event) {
+    e.stop_propagation();
+}
+
+/// Listen to the [`click`](https://developer.mozilla.org/en-US/docs/Web/API/Element/click_event) event of an element
+///
+/// This function registers the `handler` with javascript's [`addEventListener()`](https://developer.mozilla.org/en-US/docs/Web/API/EventTarget/addEventListener) method and returns a function which removes it again.
+/// It replaces the `callback` + `set_onclick` + `.forget()` dance, which leaks the closure forever.
+///
+/// Calling the returned remover removes the listener and frees the closure.
+/// If the remover is dropped without being called, the listener stays registered and the closure is leaked, like with `.forget()`.
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use wasm_bindgen::JsCast;
+/// use web_sys::HtmlElement;
+/// use weblog::console_log;
+/// use webru::{get_element_by_id, on_click};
+///
+/// let button: HtmlElement = get_element_by_id("save").unwrap().dyn_into().unwrap();
+///
+/// let remove = on_click(&button, || {
+///     console_log!("Saved!");
+/// });
+///
+/// // later, stop listening
+/// remove();
+/// ```
+pub fn on_click<T>(el: &HtmlElement, handler: T) -> impl FnOnce()
+where
+    T: Fn() + 'static,
+{
+    // not dropped unless the remover is called
+    let listener = ManuallyDrop::new(listen(el, "click", move |_| handler()));
+
+    move || ManuallyDrop::into_inner(listener).remove()
+}
+
+/// Listen to the [`input`](https://developer.mozilla.org/en-US/docs/Web/API/HTMLElement/input_event) event of an input element
+///
+/// The `handler` is called with the current [`value`](https://developer.mozilla.org/en-US/docs/Web/API/HTMLInputElement/value) of the input every time the user changes it, for example on every keystroke.
+///
+/// For checkboxes and radio buttons, the `value` is the value of the `value` attribute (`"on"` by default), not the checked state. Read [`checked()`](https://docs.rs/web-sys/0.3.56/web_sys/struct.HtmlInputElement.html#method.checked) on the element instead.
+///
+/// Just like the [`callback`](crate::callback) function, it returns the [`Closure`] of the listener. You must keep it alive as long as the listener is registered:
+/// if the `Closure` is dropped, the listener stays registered and throws an error when the event fires. Usually you want to call `.forget()` on it.
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use wasm_bindgen::JsCast;
+/// use web_sys::HtmlInputElement;
+/// use webru::{get_element_by_id, on_input};
+///
+/// let search: HtmlInputElement = get_element_by_id("search").unwrap().dyn_into().unwrap();
+/// let results = get_element_by_id("results").unwrap();
+///
+/// on_input(&search, move |query| {
+///     let msg = format!("Searching for {}...", query);
+///     results.set_text_content(Some(&msg));
+/// })
+/// .forget();
+/// ```
+///
+/// [`Closure`]: <https://docs.rs/wasm-bindgen/0.2.79/wasm_bindgen/closure/struct.Closure.html>
+pub fn on_input<T>(el: &HtmlInputElement, handler: T) -> Closure<dyn Fn(Event)>
+where
+    T: Fn(String) + 'static,
+{
+    value_listener(el, "input", handler)
+}
+
+/// Listen to the [`change`](https://developer.mozilla.org/en-US/docs/Web/API/HTMLElement/change_event) event of an input element
+///
+/// The `handler` is called with the current [`value`](https://developer.mozilla.org/en-US/docs/Web/API/HTMLInputElement/value) of the input when the user commits a change.
+/// For text inputs, that's when the input loses focus after being edited. Use [`on_input`] to be notified on every keystroke.
+///
+/// For checkboxes and radio buttons, the `value` is not the checked state. Read [`checked()`](https://docs.rs/web-sys/0.3.56/web_sys/struct.HtmlInputElement.html#method.checked) on the element instead.
+///
+/// Just like [`on_input`], it returns the [`Closure`] of the listener, which must be kept alive (usually with `.forget()`).
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+/// [`Closure`]: <https://docs.rs/wasm-bindgen/0.2.79/wasm_bindgen/closure/struct.Closure.html>
+pub fn on_change<T>(el: &HtmlInputElement, handler: T) -> Closure<dyn Fn(Event)>
+where
+    T: Fn(String) + 'static,
+{
+    value_listener(el, "change", handler)
+}
+
+/// Listen to the [`submit`](https://developer.mozilla.org/en-US/docs/Web/API/HTMLFormElement/submit_event) event of a form
+///
+/// The default action of the event is always prevented before the `handler` is called, so submitting the form never reloads the page or navigates away.
+/// This is what you want in single page applications, where the form data is sent with `fetch` instead. Use [`form_to_map`](crate::form_to_map) to read the fields.
+///
+/// Just like [`on_input`], it returns the [`Closure`] of the listener, which must be kept alive (usually with `.forget()`).
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use wasm_bindgen::JsCast;
+/// use web_sys::HtmlFormElement;
+/// use webru::{form_to_map, get_element_by_id, on_submit};
+///
+/// let form: HtmlFormElement = get_element_by_id("login").unwrap().dyn_into().unwrap();
+///
+/// on_submit(&form, {
+///     let form = form.clone();
+///     move || {
+///         let fields = form_to_map(&form);
+///         // send the fields to the server
+///     }
+/// })
+/// .forget();
+/// ```
+///
+/// [`Closure`]: <https://docs.rs/wasm-bindgen/0.2.79/wasm_bindgen/closure/struct.Closure.html>
+pub fn on_submit<T>(form: &HtmlFormElement, handler: T) -> Closure<dyn Fn(Event)>
+where
+    T: Fn() + 'static,
+{
+    closure_listener(form, "submit", move |event: Event| {
+        event.prevent_default();
+        handler();
+    })
+}
+
+/// Listen to the [`keydown`](https://developer.mozilla.org/en-US/docs/Web/API/Element/keydown_event) event
+///
+/// The `handler` is called with the [`key`](https://developer.mozilla.org/en-US/docs/Web/API/KeyboardEvent/key) of the pressed key, like `"Escape"`, `"Enter"` or `"a"`.
+/// Holding a key down fires the event repeatedly.
+///
+/// Only the key is passed. If you need the modifier state (`ctrl_key`, `shift_key`, ...), register a [`callback_with_arg`](crate::callback_with_arg) receiving the [`KeyboardEvent`](https://docs.rs/web-sys/0.3.56/web_sys/struct.KeyboardEvent.html) instead.
+///
+/// Just like [`on_input`], it returns the [`Closure`] of the listener, which must be kept alive (usually with `.forget()`).
+///
+///
+/// # Arguments
+///
+/// * `target` - The element, the document or the window to listen on. Elements only receive keyboard events while they (or one of their children) have the focus
+///
+/// * `handler` - The Rust closure receiving the key
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use webru::{document, get_element_by_id, on_keydown};
+///
+/// // press Escape to close the modal
+/// on_keydown(&document(), |key| {
+///     if key == "Escape" {
+///         get_element_by_id("modal").unwrap().remove();
+///     }
+/// })
+/// .forget();
+/// ```
+///
+/// [`Closure`]: <https://docs.rs/wasm-bindgen/0.2.79/wasm_bindgen/closure/struct.Closure.html>
+pub fn on_keydown<T>(target: &EventTarget, handler: T) -> Closure<dyn Fn(KeyboardEvent)>
+where
+    T: Fn(String) + 'static,
+{
+    closure_listener(target, "keydown", move |event: KeyboardEvent| {
+        handler(event.key())
+    })
+}
+
+/// Listen to the [`keyup`](https://developer.mozilla.org/en-US/docs/Web/API/Element/keyup_event) event
+///
+/// The `handler` is called with the [`key`](https://developer.mozilla.org/en-US/docs/Web/API/KeyboardEvent/key) of the released key. See [`on_keydown`] for the details.
+///
+/// Just like [`on_keydown`], it returns the `Closure` of the listener, which must be kept alive (usually with `.forget()`).
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+pub fn on_keyup<T>(target: &EventTarget, handler: T) -> Closure<dyn Fn(KeyboardEvent)>
+where
+    T: Fn(String) + 'static,
+{
+    closure_listener(target, "keyup", move |event: KeyboardEvent| {
+        handler(event.key())
+    })
+}
+
+/// Register a listener on `el` for `event` which passes the value of the input to `handler`
+fn value_listener<T>(el: &HtmlInputElement, event: &str, handler: T) -> Closure<dyn Fn(Event)>
+where
+    T: Fn(String) + 'static,
+{
+    let input = el.clone();
+
+    closure_listener(el, event, move |_: Event| handler(input.value()))
+}
+
+/// Register `handler` on `target` for `event` and return its `Closure`
+fn closure_listener<E, T>(target: &EventTarget, event: &str, handler: T) -> Closure<dyn Fn(E)>
+where
+    dyn Fn(E): WasmClosure,
+    T: Fn(E) + 'static,
+{
+    let closure = Closure::wrap(Box::new(handler) as Box<dyn Fn(E)>);
+
+    target
+        .add_event_listener_with_callback(event, closure.as_ref().unchecked_ref())
+        .unwrap();
+
+    closure
+}
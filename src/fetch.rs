@@ -0,0 +1,125 @@
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{window, AbortController, AbortSignal, RequestInit, Response};
+
+/// Fetch the text of a url, with a signal to cancel the request
+///
+/// This function uses javascript's [`fetch()`](https://developer.mozilla.org/en-US/docs/Web/API/fetch) method and reads the body of the response as text.
+/// The `signal` is passed to the request, so calling `abort()` on its [`AbortController`](https://developer.mozilla.org/en-US/docs/Web/API/AbortController) cancels it.
+/// This is useful to drop stale requests, for example the previous request of a search box when the user keeps typing.
+///
+/// It returns `Err` if:
+///
+/// * The request is aborted. The error is the `AbortError` thrown by `fetch()`
+///
+/// * The request fails because of a network error
+///
+/// * The response has an HTTP error status (not `2xx`), like `404`
+///
+///
+/// # Arguments
+///
+/// * `url` - The url to fetch
+///
+/// * `signal` - The signal which cancels the request
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use web_sys::AbortController;
+/// use weblog::console_log;
+/// use webru::fetch_text_abortable;
+///
+/// let controller = AbortController::new().unwrap();
+/// let signal = controller.signal();
+///
+/// wasm_bindgen_futures::spawn_local(async move {
+///     match fetch_text_abortable("/api/search?q=rust", &signal).await {
+///         Ok(text) => console_log!(text),
+///         Err(_) => console_log!("The request failed or was aborted"),
+///     }
+/// });
+///
+/// // the user typed again, cancel the request
+/// controller.abort();
+/// ```
+pub async fn fetch_text_abortable(url: &str, signal: &AbortSignal) -> Result<String, JsValue> {
+    let init = RequestInit::new();
+    init.set_signal(Some(signal));
+
+    let promise = window().unwrap().fetch_with_str_and_init(url, &init);
+
+    let response: Response = JsFuture::from(promise).await?.dyn_into()?;
+
+    if !response.ok() {
+        return Err(JsValue::from_str(&format!(
+            "request failed with status {}",
+            response.status()
+        )));
+    }
+
+    let text = JsFuture::from(response.text()?).await?;
+
+    Ok(text.as_string().unwrap_or_default())
+}
+
+/// Create a new [`AbortController`](https://developer.mozilla.org/en-US/docs/Web/API/AbortController)
+///
+/// Its signal (`controller.signal()`) can be passed to functions like [`fetch_text_abortable`] or [`set_timeout_with_signal`](crate::set_timeout_with_signal), and `controller.abort()` cancels all of them at once.
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use webru::{abort_controller, set_timeout_with_signal};
+///
+/// let controller = abort_controller();
+///
+/// set_timeout_with_signal(|| {}, 1000, &controller.signal()).unwrap();
+///
+/// controller.abort();
+/// ```
+pub fn abort_controller() -> AbortController {
+    AbortController::new().unwrap()
+}
+
+/// Create a new [`AbortController`](https://developer.mozilla.org/en-US/docs/Web/API/AbortController) and return it with its signal
+///
+/// This function does the same thing as the [`abort_controller`] function does, but it also returns `controller.signal()`, which is often needed right away.
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use webru::{abort_controller_with_signal, fetch_text_abortable};
+///
+/// let (controller, signal) = abort_controller_with_signal();
+///
+/// wasm_bindgen_futures::spawn_local(async move {
+///     let _ = fetch_text_abortable("/api/search?q=rust", &signal).await;
+/// });
+///
+/// controller.abort();
+/// ```
+pub fn abort_controller_with_signal() -> (AbortController, AbortSignal) {
+    let controller = abort_controller();
+    let signal = controller.signal();
+
+    (controller, signal)
+}
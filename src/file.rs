@@ -0,0 +1,144 @@
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::JsCast;
+use web_sys::{DragEvent, Element, File, FileList, HtmlInputElement};
+
+use crate::create_element;
+use crate::events::{listen, EventListenerHandle};
+
+/// Open the browser's file picker
+///
+/// This function creates a hidden `<input type="file">` element and clicks it, so you can open the file picker from any button instead of styling a file input.
+/// When the user selects files, `on_files` is called with them.
+///
+/// Browsers only open the picker inside a user gesture, so call this function from a click handler.
+/// If the user cancels the picker, `on_files` is never called.
+///
+///
+/// # Arguments
+///
+/// * `accept` - The [file types](https://developer.mozilla.org/en-US/docs/Web/HTML/Attributes/accept) the user can pick, like `image/*` or `.pdf,.docx`. An empty string accepts every file
+///
+/// * `multiple` - Whether the user can select more than one file
+///
+/// * `on_files` - The Rust closure receiving the selected files
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use wasm_bindgen::JsCast;
+/// use weblog::console_log;
+/// use webru::{callback, get_element_by_id, open_file_picker};
+///
+/// let onclick = callback(|| {
+///     open_file_picker("image/*", true, |files| {
+///         for file in files {
+///             console_log!("Selected ", file.name());
+///         }
+///     });
+/// });
+///
+/// get_element_by_id("upload")
+///     .unwrap()
+///     .add_event_listener_with_callback("click", onclick.as_ref().unchecked_ref())
+///     .unwrap();
+///
+/// onclick.forget();
+/// ```
+pub fn open_file_picker<T>(accept: &str, multiple: bool, on_files: T)
+where
+    T: FnOnce(Vec<File>) + 'static,
+{
+    let input = create_element("input")
+        .dyn_into::<HtmlInputElement>()
+        .unwrap();
+
+    input.set_type("file");
+    input.set_accept(accept);
+    input.set_multiple(multiple);
+
+    // the closure frees itself after the first call
+    let onchange = Closure::once_into_js({
+        let input = input.clone();
+        move || on_files(input.files().map(file_list_to_vec).unwrap_or_default())
+    });
+
+    input
+        .add_event_listener_with_callback("change", onchange.unchecked_ref())
+        .unwrap();
+
+    input.click();
+}
+
+/// Turn an element into a drop zone for files
+///
+/// When the user drags files from the operating system and drops them on the element, `on_files` is called with them.
+///
+/// The browser opens dropped files by default, so this function calls `preventDefault()` on the element's [`dragover`](https://developer.mozilla.org/en-US/docs/Web/API/HTMLElement/dragover_event) and [`drop`](https://developer.mozilla.org/en-US/docs/Web/API/HTMLElement/drop_event) events.
+/// The files are read from the [`DataTransfer`](https://developer.mozilla.org/en-US/docs/Web/API/DataTransfer) of the `drop` event. Drops without files (like dragged text) are ignored.
+///
+/// The listeners are removed when the returned [`EventListenerHandle`] is dropped.
+///
+///
+/// # Arguments
+///
+/// * `el` - The drop zone
+///
+/// * `on_files` - The Rust closure receiving the dropped files
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use weblog::console_log;
+/// use webru::{get_element_by_id, on_file_drop};
+///
+/// let drop_zone = get_element_by_id("drop-zone").unwrap();
+///
+/// let listener = on_file_drop(&drop_zone, |files| {
+///     for file in files {
+///         console_log!("Dropped ", file.name());
+///     }
+/// });
+///
+/// listener.forget();
+/// ```
+pub fn on_file_drop<T>(el: &Element, mut on_files: T) -> EventListenerHandle
+where
+    T: FnMut(Vec<File>) + 'static,
+{
+    // without this, the `drop` event doesn't fire
+    let dragover = listen(el, "dragover", |event| event.prevent_default());
+
+    let drop = listen(el, "drop", move |event| {
+        event.prevent_default();
+
+        let files = event
+            .dyn_ref::<DragEvent>()
+            .and_then(|event| event.data_transfer())
+            .and_then(|data| data.files())
+            .map(file_list_to_vec)
+            .unwrap_or_default();
+
+        if !files.is_empty() {
+            on_files(files);
+        }
+    });
+
+    dragover.join(drop)
+}
+
+/// Collect a [`FileList`] into a `Vec`
+pub(crate) fn file_list_to_vec(list: FileList) -> Vec<File> {
+    (0..list.length()).filter_map(|i| list.get(i)).collect()
+}
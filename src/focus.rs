@@ -0,0 +1,109 @@
+use wasm_bindgen::JsCast;
+use web_sys::{Element, HtmlElement, KeyboardEvent};
+
+use crate::document;
+use crate::events::{listen, EventListenerHandle};
+
+/// CSS selector matching the elements which can receive keyboard focus
+const FOCUSABLE: &str = "a[href], area[href], button:not([disabled]), input:not([disabled]):not([type=\"hidden\"]), select:not([disabled]), textarea:not([disabled]), iframe, [contenteditable], [tabindex]:not([tabindex=\"-1\"])";
+
+/// Trap the keyboard focus inside an element
+///
+/// Accessible modals and dialogs must not let the `Tab` key move the focus to the page behind them.
+/// While a `FocusTrap` is active, pressing `Tab` on the last focusable element of the container moves the focus to the first one,
+/// and pressing `Shift+Tab` on the first one moves the focus to the last one.
+///
+/// The trap is released when the `FocusTrap` is dropped or when the [`release`](FocusTrap::release) method is called.
+///
+/// # Panics
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+/// # Example
+///
+/// ```no_run
+/// use webru::{body, create_element, FocusTrap};
+///
+/// let modal = create_element("div");
+/// modal.set_inner_html("<input><button>Ok</button><button>Cancel</button>");
+///
+/// body().append_child(&modal).unwrap();
+///
+/// // The focus can't leave the modal while `trap` is alive
+/// let trap: FocusTrap = FocusTrap::activate(&modal);
+///
+/// // ...
+///
+/// // When the modal is closed
+/// trap.release();
+/// modal.remove();
+/// ```
+pub struct FocusTrap {
+    _listener: EventListenerHandle,
+}
+
+impl FocusTrap {
+    /// Starts trapping the focus inside the `container`.
+    ///
+    pub fn activate(container: &Element) -> Self {
+        let container = container.clone();
+
+        let listener = listen(&document(), "keydown", move |event| {
+            let event = event.dyn_into::<KeyboardEvent>().unwrap();
+
+            if event.key() != "Tab" {
+                return;
+            }
+
+            let focusable: Vec<HtmlElement> = {
+                let list = container.query_selector_all(FOCUSABLE).unwrap();
+
+                (0..list.length())
+                    .filter_map(|i| list.item(i))
+                    .filter_map(|node| node.dyn_into::<HtmlElement>().ok())
+                    .collect()
+            };
+
+            let (first, last) = match (focusable.first(), focusable.last()) {
+                (Some(first), Some(last)) => (first, last),
+                // nothing to focus inside the container, so don't let the focus leave it
+                _ => {
+                    event.prevent_default();
+                    return;
+                }
+            };
+
+            let active = document().active_element();
+            let inside = active
+                .as_ref()
+                .map(|active| container.contains(Some(active)))
+                .unwrap_or(false);
+
+            let target = if !inside {
+                Some(first)
+            } else if event.shift_key() && active.as_ref() == Some(first.as_ref()) {
+                Some(last)
+            } else if !event.shift_key() && active.as_ref() == Some(last.as_ref()) {
+                Some(first)
+            } else {
+                None
+            };
+
+            if let Some(target) = target {
+                event.prevent_default();
+                target.focus().unwrap();
+            }
+        });
+
+        Self {
+            _listener: listener,
+        }
+    }
+
+    /// Releases the trap.
+    ///
+    /// This is the same as dropping the `FocusTrap`.
+    ///
+    pub fn release(self) {
+        drop(self);
+    }
+}
@@ -0,0 +1,323 @@
+use js_sys::Array;
+use wasm_bindgen::JsCast;
+use web_sys::{FormData, HtmlElement, HtmlFormElement, HtmlInputElement};
+
+use std::collections::HashMap;
+
+use crate::events::{listen, EventListenerHandle};
+use crate::{document, get_element_by_id};
+
+/// Get all the `<form>` elements of the document
+///
+/// This function is equivalent to javascript's [`document.forms`](https://developer.mozilla.org/en-US/docs/Web/API/Document/forms) property,
+/// but instead of returning an `HTMLCollection` it returns [`Vec<HtmlFormElement>`]
+///
+/// The forms are sorted as they appear in the document.
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use webru::forms;
+/// use weblog::console_log;
+///
+/// for form in forms() {
+///     console_log!("Form: ", form.name());
+/// }
+/// ```
+///
+/// [`Vec<HtmlFormElement>`]: <https://docs.rs/web-sys/0.3.56/web_sys/struct.HtmlFormElement.html>
+pub fn forms() -> Vec<HtmlFormElement> {
+    let collection = document().forms();
+
+    (0..collection.length())
+        .filter_map(|i| collection.item(i))
+        .filter_map(|element| element.dyn_into::<HtmlFormElement>().ok())
+        .collect()
+}
+
+/// Enable or disable a form control
+///
+/// This function adds or removes the [`disabled`](https://developer.mozilla.org/en-US/docs/Web/HTML/Attributes/disabled) attribute,
+/// which is reflected by the `disabled` property of `<button>`, `<input>`, `<select>`, `<textarea>`, `<fieldset>` and friends.
+///
+///
+/// # Arguments
+///
+/// * `el` - The element to enable or disable
+///
+/// * `disabled` - `true` to disable the element, `false` to enable it
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use wasm_bindgen::JsCast;
+/// use web_sys::HtmlElement;
+/// use webru::{get_element_by_id, is_disabled, set_disabled};
+///
+/// let submit: HtmlElement = get_element_by_id("submit")
+///     .unwrap()
+///     .dyn_into::<HtmlElement>()
+///     .unwrap();
+///
+/// // prevent double submission
+/// set_disabled(&submit, true);
+///
+/// assert!(is_disabled(&submit));
+/// ```
+pub fn set_disabled(el: &HtmlElement, disabled: bool) {
+    el.toggle_attribute_with_force("disabled", disabled)
+        .unwrap();
+}
+
+/// Check if an element is disabled
+///
+/// It returns `true` if the element has the `disabled` attribute,
+/// or if it's a form control which is disabled by a disabled ancestor `<fieldset>` (the CSS [`:disabled`](https://developer.mozilla.org/en-US/docs/Web/CSS/:disabled) pseudo-class).
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+pub fn is_disabled(el: &HtmlElement) -> bool {
+    el.has_attribute("disabled") || el.matches(":disabled").unwrap()
+}
+
+/// Select all the text of an `<input>` element
+///
+/// This function is equivalent to javascript's [`HTMLInputElement.select()`](https://developer.mozilla.org/en-US/docs/Web/API/HTMLInputElement/select) method.
+/// It's useful for "click to select" fields like share links.
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use wasm_bindgen::JsCast;
+/// use web_sys::HtmlInputElement;
+/// use webru::{create_element, select_input_text};
+///
+/// let input = create_element("input")
+///     .dyn_into::<HtmlInputElement>()
+///     .unwrap();
+///
+/// input.set_value("https://example.com/share/42");
+///
+/// select_input_text(&input);
+/// ```
+pub fn select_input_text(el: &HtmlInputElement) {
+    el.select();
+}
+
+/// Select a part of the text of an `<input>` element
+///
+/// This function is equivalent to javascript's [`HTMLInputElement.setSelectionRange()`](https://developer.mozilla.org/en-US/docs/Web/API/HTMLInputElement/setSelectionRange) method.
+///
+///
+/// # Arguments
+///
+/// * `el` - The input element
+///
+/// * `start` - The index of the first selected character
+///
+/// * `end` - The index after the last selected character. Values greater than the length of the value select until the end
+///
+///
+/// # Panics
+///
+/// * This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+/// * This function will panic if the input's type doesn't support selection, for example `number` or `email`
+///
+pub fn set_selection_range(el: &HtmlInputElement, start: u32, end: u32) {
+    el.set_selection_range(start, end).unwrap();
+}
+
+/// Check if a form passes the HTML5 validation
+///
+/// This function is equivalent to javascript's [`HTMLFormElement.checkValidity()`](https://developer.mozilla.org/en-US/docs/Web/API/HTMLFormElement/checkValidity) method.
+/// It returns `false` if any control of the form violates its constraints, like `required`, `pattern`, `min` or `type="email"`.
+///
+/// No message is shown to the user. Use [`report_form_validity`] for that.
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+pub fn is_form_valid(form: &HtmlFormElement) -> bool {
+    form.check_validity()
+}
+
+/// Check if a form passes the HTML5 validation and show the browser's messages if it doesn't
+///
+/// This function is equivalent to javascript's [`HTMLFormElement.reportValidity()`](https://developer.mozilla.org/en-US/docs/Web/API/HTMLFormElement/reportValidity) method.
+/// It returns the same value as [`is_form_valid`], but the browser also shows its native validation message on the first invalid control.
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use wasm_bindgen::JsCast;
+/// use web_sys::HtmlFormElement;
+/// use webru::{get_element_by_id, report_form_validity};
+///
+/// let form = get_element_by_id("signup")
+///     .unwrap()
+///     .dyn_into::<HtmlFormElement>()
+///     .unwrap();
+///
+/// if report_form_validity(&form) {
+///     // send the form
+/// }
+/// ```
+pub fn report_form_validity(form: &HtmlFormElement) -> bool {
+    form.report_validity()
+}
+
+/// Get the values of a form's fields as a map
+///
+/// This function reads the fields with javascript's [`FormData`](https://developer.mozilla.org/en-US/docs/Web/API/FormData) API, so it includes the same values the browser would submit:
+/// fields need a `name`, disabled fields are skipped, and unchecked checkboxes and radio buttons are not included.
+///
+/// If several fields have the same name (like a group of checkboxes), the last value is kept. Use [`form_to_pairs`] to get all of them.
+/// File inputs are skipped.
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use wasm_bindgen::JsCast;
+/// use web_sys::HtmlFormElement;
+/// use webru::{form_to_map, get_element_by_id};
+///
+/// let form = get_element_by_id("login")
+///     .unwrap()
+///     .dyn_into::<HtmlFormElement>()
+///     .unwrap();
+///
+/// let values = form_to_map(&form);
+///
+/// let username = &values["username"];
+/// ```
+pub fn form_to_map(form: &HtmlFormElement) -> HashMap<String, String> {
+    form_to_pairs(form).into_iter().collect()
+}
+
+/// Get the values of a form's fields as `(name, value)` pairs
+///
+/// This function does the same thing as the [`form_to_map`] function does, but it keeps every value of the fields with the same name, in the order they appear in the form.
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use wasm_bindgen::JsCast;
+/// use web_sys::HtmlFormElement;
+/// use webru::{form_to_pairs, get_element_by_id};
+///
+/// let form = get_element_by_id("filters")
+///     .unwrap()
+///     .dyn_into::<HtmlFormElement>()
+///     .unwrap();
+///
+/// // all the checked `<input type="checkbox" name="tag">`
+/// let tags: Vec<String> = form_to_pairs(&form)
+///     .into_iter()
+///     .filter(|(name, _)| name == "tag")
+///     .map(|(_, value)| value)
+///     .collect();
+/// ```
+pub fn form_to_pairs(form: &HtmlFormElement) -> Vec<(String, String)> {
+    let data = FormData::new_with_form(form).unwrap();
+
+    js_sys::try_iter(&data)
+        .unwrap()
+        .unwrap()
+        .filter_map(|entry| {
+            let entry: Array = entry.unwrap().unchecked_into();
+
+            // the value of a file input is a `File`, not a string
+            Some((entry.get(0).as_string()?, entry.get(1).as_string()?))
+        })
+        .collect()
+}
+
+/// Mirror the value of an `<input>` element into a closure
+///
+/// The `on_change` closure is called once immediately with the current value of the input, and then on every [`input`](https://developer.mozilla.org/en-US/docs/Web/API/HTMLElement/input_event) event with the new value.
+/// So it always sees the latest value, which makes it a tiny building block for two-way binding.
+///
+/// The listener is removed when the returned [`EventListenerHandle`] is dropped.
+///
+///
+/// # Arguments
+///
+/// * `id` - The id of the `<input>` element
+///
+/// * `on_change` - The Rust closure receiving the value of the input
+///
+///
+/// # Panics
+///
+/// * This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+/// * This function will panic if there is no element with the `id` or if it isn't an `<input>` element
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use weblog::console_log;
+/// use webru::bind_input;
+///
+/// let binding = bind_input("username", |value| {
+///     console_log!("Hello ", value);
+/// });
+///
+/// // keep the binding for the lifetime of the page
+/// binding.forget();
+/// ```
+pub fn bind_input<T>(id: &str, mut on_change: T) -> EventListenerHandle
+where
+    T: FnMut(String) + 'static,
+{
+    let input = get_element_by_id(id)
+        .and_then(|el| el.dyn_into::<HtmlInputElement>().ok())
+        .unwrap_or_else(|| panic!("no <input> element with id '{}'", id));
+
+    on_change(input.value());
+
+    listen(&input.clone(), "input", move |_| on_change(input.value()))
+}
@@ -0,0 +1,91 @@
+use js_sys::{Promise, Reflect};
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::window;
+
+/// Get the current position of the device
+///
+/// This function wraps javascript's [`navigator.geolocation.getCurrentPosition()`](https://developer.mozilla.org/en-US/docs/Web/API/Geolocation/getCurrentPosition) method in a future,
+/// and returns the position as `(latitude, longitude)` in degrees.
+///
+/// The Geolocation API only works in [secure contexts](https://developer.mozilla.org/en-US/docs/Web/Security/Secure_Contexts) (`https://` and `localhost`),
+/// and the browser asks the user for permission the first time it's used. So the future may take a while to resolve.
+///
+/// It returns `Err` with the [error code](https://developer.mozilla.org/en-US/docs/Web/API/GeolocationPositionError/code) as a number if the position couldn't be determined:
+///
+/// * `1` - The user denied the permission
+///
+/// * `2` - The position is unavailable, for example because there is no GPS signal
+///
+/// * `3` - Getting the position timed out
+///
+/// If the browser doesn't support the Geolocation API (or the page is not a secure context), it returns `Err` with a message.
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use weblog::console_log;
+/// use webru::get_current_position;
+///
+/// wasm_bindgen_futures::spawn_local(async {
+///     match get_current_position().await {
+///         Ok((latitude, longitude)) => console_log!("You are at ", latitude, ", ", longitude),
+///         Err(code) if code == 1 => console_log!("Permission denied"),
+///         Err(_) => console_log!("Couldn't get your position"),
+///     }
+/// });
+/// ```
+pub async fn get_current_position() -> Result<(f64, f64), JsValue> {
+    let navigator = window().unwrap().navigator();
+
+    // `navigator.geolocation` is `undefined` in insecure contexts of some browsers
+    let geolocation = Reflect::get(&navigator, &"geolocation".into())?;
+
+    if geolocation.is_undefined() || geolocation.is_null() {
+        return Err(JsValue::from_str("the Geolocation API is not available"));
+    }
+
+    let geolocation = navigator.geolocation()?;
+
+    let mut result = Ok(());
+    let mut on_error = None;
+
+    let promise = Promise::new(&mut |resolve, reject| {
+        // reject with the error code instead of the error object
+        let closure = Closure::wrap(Box::new(move |error: JsValue| {
+            let code = Reflect::get(&error, &"code".into()).unwrap_or(error);
+            reject.call1(&JsValue::NULL, &code).unwrap();
+        }) as Box<dyn FnMut(JsValue)>);
+
+        result = geolocation.get_current_position_with_error_callback(
+            &resolve,
+            Some(closure.as_ref().unchecked_ref()),
+        );
+
+        on_error = Some(closure);
+    });
+
+    result?;
+
+    let position = JsFuture::from(promise).await;
+
+    // the error callback isn't called anymore
+    drop(on_error);
+
+    let position = position?;
+    let coords = Reflect::get(&position, &"coords".into())?;
+
+    let latitude = Reflect::get(&coords, &"latitude".into())?.as_f64().unwrap();
+    let longitude = Reflect::get(&coords, &"longitude".into())?
+        .as_f64()
+        .unwrap();
+
+    Ok((latitude, longitude))
+}
@@ -1,6 +1,9 @@
+use js_sys::Array;
 use wasm_bindgen::closure::Closure;
 use wasm_bindgen::JsCast;
-use web_sys::{window, Document, Element, HtmlElement, Location};
+use web_sys::{window, Document, Element, HtmlElement, Location, UrlSearchParams, Window};
+
+use std::collections::HashMap;
 
 /// Get the [`web_sys::Document`](https://docs.rs/web-sys/0.3.56/web_sys/struct.Document.html) Object
 ///
@@ -14,7 +17,7 @@ use web_sys::{window, Document, Element, HtmlElement, Location};
 ///
 /// # Example    
 ///
-/// ```rust
+/// ```no_run
 /// use weblog::console_log;
 /// use webru::document;
 ///
@@ -40,8 +43,48 @@ use web_sys::{window, Document, Element, HtmlElement, Location};
 ///
 ///    
 pub fn document() -> Document {
-    let window = window().unwrap();
-    window.document().unwrap()
+    try_document().expect("`document` is not available outside of the browser")
+}
+
+/// Get the [`web_sys::Window`](https://docs.rs/web-sys/0.3.56/web_sys/struct.Window.html) object without panicking
+///
+/// This function is equivalent to javascript's `window` property
+///
+/// It returns `None` outside of the browser, for example when the code runs in `node.js` or in a web worker. So library code can check the environment and fail gracefully instead of panicking.
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use webru::try_window;
+///
+/// if let Some(window) = try_window() {
+///     window.alert_with_message("Hello world").unwrap();
+/// }
+/// ```
+pub fn try_window() -> Option<Window> {
+    window()
+}
+
+/// Get the [`web_sys::Document`](https://docs.rs/web-sys/0.3.56/web_sys/struct.Document.html) object without panicking
+///
+/// This function does the same thing as the [`document`] function does, but it returns `None` instead of panicking when there is no `window` or no `document`
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use webru::try_document;
+///
+/// match try_document() {
+///     Some(document) => document.set_title("Hello world"),
+///     None => {
+///         // pre-rendering on the server, there is nothing to do
+///     }
+/// }
+/// ```
+pub fn try_document() -> Option<Document> {
+    try_window()?.document()
 }
 
 /// Get the [`web_sys::Location`](https://docs.rs/web-sys/0.3.56/web_sys/struct.Location.html) object
@@ -56,7 +99,7 @@ pub fn document() -> Document {
 ///
 /// # Example
 ///
-/// ```rust
+/// ```no_run
 /// use weblog::console_log;
 /// use webru::location;
 ///
@@ -104,7 +147,7 @@ pub fn url() -> String {
 ///
 /// # Example
 ///
-/// ```rust
+/// ```no_run
 /// use webru::create_element;
 /// use webru::body;
 ///
@@ -168,6 +211,148 @@ pub fn path_name() -> String {
     location().pathname().unwrap()
 }
 
+/// Get the path of the website split into its segments
+///
+/// This function takes the [`path_name`] and splits it on `/`, ignoring the empty segments created by leading, trailing or repeated slashes
+///
+/// If your website's url is `https://www.example.com/users/42/edit` then it will return `["users", "42", "edit"]`
+///
+/// If your website's url is `https://www.example.com/users/` then it will return `["users"]`
+///
+/// If your website's url is `https://www.example.com` then it will return an empty `Vec`
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+pub fn path_segments() -> Vec<String> {
+    path_name()
+        .split('/')
+        .filter(|segment| !segment.is_empty())
+        .map(|segment| segment.to_string())
+        .collect()
+}
+
+/// Get the query parameters of the website's url
+///
+/// This function parses javascript's [`location.search`](https://developer.mozilla.org/en-US/docs/Web/API/Location/search) property with [`URLSearchParams`](https://developer.mozilla.org/en-US/docs/Web/API/URLSearchParams),
+/// so the keys and the values are decoded (`%20` and `+` become spaces).
+///
+/// If your website's url is `https://www.example.com/search?q=rust&page=2` then it will return a map with `q => rust` and `page => 2`
+///
+/// If a key is repeated, like in `?tag=a&tag=b`, the last value (`b`) is kept. A key without a value, like in `?debug`, has an empty value.
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use webru::query_params;
+///
+/// let params = query_params();
+///
+/// let page: u32 = params
+///     .get("page")
+///     .and_then(|page| page.parse().ok())
+///     .unwrap_or(1);
+/// ```
+pub fn query_params() -> HashMap<String, String> {
+    parse_params(&location().search().unwrap())
+}
+
+/// Get a query parameter of the website's url
+///
+/// This function returns the value of `key` in the [`query_params`]. So if the key is repeated, the last value is returned.
+///
+/// If your website's url is `https://www.example.com/search?q=rust` then `query_param("q")` will return `Some("rust")` and `query_param("page")` will return `None`
+///
+///
+/// # Arguments
+///
+/// * `key` - The name of the query parameter
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+pub fn query_param(key: &str) -> Option<String> {
+    query_params().remove(key)
+}
+
+/// Get the query parameters of the hash of the website's url
+///
+/// Some routers keep their state in the hash, like `https://www.example.com/#/search?q=rust&page=2`.
+/// This function takes the part of javascript's [`location.hash`](https://developer.mozilla.org/en-US/docs/Web/API/Location/hash) property after the first `?` and parses it like [`query_params`].
+///
+/// So for the url above, it will return a map with `q => rust` and `page => 2`. If the hash has no `?`, it returns an empty map.
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+pub fn hash_params() -> HashMap<String, String> {
+    let hash = location().hash().unwrap();
+
+    match hash.split_once('?') {
+        Some((_, query)) => parse_params(query),
+        None => HashMap::new(),
+    }
+}
+
+/// Parse a query string with `URLSearchParams`, keeping the last value of repeated keys
+fn parse_params(query: &str) -> HashMap<String, String> {
+    let params = UrlSearchParams::new_with_str(query).unwrap();
+
+    js_sys::try_iter(&params)
+        .unwrap()
+        .unwrap()
+        .map(|entry| {
+            let entry: Array = entry.unwrap().unchecked_into();
+            (
+                entry.get(0).as_string().unwrap(),
+                entry.get(1).as_string().unwrap(),
+            )
+        })
+        .collect()
+}
+
+/// Get the character encoding of the document
+///
+/// This function is equivalent to javascript's [`document.characterSet`](https://developer.mozilla.org/en-US/docs/Web/API/Document/characterSet) property
+///
+/// It returns the encoding used to render the document, which is usually `UTF-8`
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+pub fn charset() -> String {
+    document().character_set()
+}
+
+/// Get the url of the page which linked to the current page
+///
+/// This function is equivalent to javascript's [`document.referrer`](https://developer.mozilla.org/en-US/docs/Web/API/Document/referrer) property
+///
+/// If the user navigated to the page directly (for example by typing the url or using a bookmark), or if the referrer was hidden by a [`Referrer-Policy`](https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Referrer-Policy), it will return an empty string
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+pub fn referrer() -> String {
+    document().referrer()
+}
+
 /// Reloads the page
 ///
 /// This function is equivalent to javascript's `location.reload()` function
@@ -196,7 +381,7 @@ pub fn reload() {
 ///
 /// # Example
 ///
-/// ```rust
+/// ```no_run
 /// use webru::alert;
 ///
 /// // show an popup/alert message
@@ -228,7 +413,7 @@ pub fn alert(msg: &str) {
 ///
 /// # Example
 ///
-/// ```rust
+/// ```no_run
 /// use webru::prompt;
 /// use weblog::console_log;
 ///
@@ -246,6 +431,41 @@ pub fn prompt(msg: &str) -> Option<String> {
     window().unwrap().prompt_with_message(msg).unwrap() // if None: it means the user clicked "cancel" button, else it means the user clicked "OK" button
 }
 
+/// Javascript [`confirm`](https://developer.mozilla.org/en-US/docs/Web/API/Window/confirm) method
+///
+/// It shows a dialog box with a message and the `OK` and `Cancel` buttons
+///
+/// If the user clicks on the `OK` button then this function will return `true`, else if the user clicks on the `Cancel` button (or closes the dialog) it will return `false`
+///
+/// Just like [`alert`] and [`prompt`], this dialog blocks the main thread until the user closes it. So no timers or event handlers run in the meantime.
+///
+///
+/// # Arguments
+///
+/// * `msg` - The text to display in the dialog box
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use webru::confirm;
+/// use weblog::console_log;
+///
+/// if confirm("Do you really want to delete this file?") {
+///     console_log!("Deleting the file");
+/// } else {
+///     console_log!("The file is not deleted");
+/// }
+/// ```
+pub fn confirm(msg: &str) -> bool {
+    window().unwrap().confirm_with_message(msg).unwrap()
+}
+
 /// Javascript [`Callback`](https://developer.mozilla.org/en-US/docs/Glossary/Callback_function)
 ///
 /// [`web-sys`](https://crates.io/crates/web-sys) uses [`Function`] for taking closures from Rust and pass it to javascript.
@@ -267,7 +487,7 @@ pub fn prompt(msg: &str) -> Option<String> {
 ///
 /// Javascript's `setTimeout` function
 ///
-/// ```rust
+/// ```no_run
 /// use wasm_bindgen::JsCast;
 /// use webru::callback;
 /// use weblog::console_log;
@@ -300,7 +520,7 @@ pub fn prompt(msg: &str) -> Option<String> {
 ///
 /// `onclick` property
 ///
-/// ```rust
+/// ```no_run
 /// use webru::{body, callback, create_element};
 /// use web_sys::HtmlElement;
 /// use wasm_bindgen::JsCast;
@@ -338,13 +558,56 @@ pub fn prompt(msg: &str) -> Option<String> {
 /// [`Closure<dyn Fn()>`]: <https://docs.rs/wasm-bindgen/0.2.79/wasm_bindgen/closure/struct.Closure.html>
 /// [`Function`]: <https://docs.rs/js-sys/0.3.56/js_sys/struct.Function.html>
 ///
-pub fn callback<T: 'static>(handler: T) -> Closure<dyn Fn()>
+pub fn callback<T>(handler: T) -> Closure<dyn Fn()>
 where
-    T: Fn(),
+    T: Fn() + 'static,
 {
     Closure::wrap(Box::new(handler) as Box<dyn Fn()>)
 }
 
+/// Javascript [`Callback`](https://developer.mozilla.org/en-US/docs/Glossary/Callback_function) which takes one argument
+///
+/// This function does the same thing as the [`callback`] function does, but the Rust closure receives the argument javascript passes to the callback.
+///
+/// Most DOM event handlers pass an event object, so `A` is usually an event type such as [`web_sys::Event`](https://docs.rs/web-sys/0.3.56/web_sys/struct.Event.html), [`web_sys::MouseEvent`](https://docs.rs/web-sys/0.3.56/web_sys/struct.MouseEvent.html), [`web_sys::KeyboardEvent`](https://docs.rs/web-sys/0.3.56/web_sys/struct.KeyboardEvent.html) or [`web_sys::InputEvent`](https://docs.rs/web-sys/0.3.56/web_sys/struct.InputEvent.html).
+/// Any type which can be received from javascript (it implements [`FromWasmAbi`]) works, including [`JsValue`](wasm_bindgen::JsValue).
+///
+/// Note that the argument is not type checked: if javascript passes an object of another type, calling its methods will fail.
+///
+///
+/// # Arguments
+///
+/// * `handler` - A Rust closure taking one argument
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use wasm_bindgen::JsCast;
+/// use web_sys::{HtmlElement, MouseEvent};
+/// use weblog::console_log;
+/// use webru::{body, callback_with_arg, create_element};
+///
+/// let button: HtmlElement = create_element("button")
+///     .dyn_ref::<HtmlElement>()
+///     .unwrap()
+///     .clone();
+///
+/// button.set_inner_html("click me");
+///
+/// let onclick = callback_with_arg(|event: MouseEvent| {
+///     console_log!("Clicked at x: ", event.client_x());
+/// });
+///
+/// button.set_onclick(Some(onclick.as_ref().unchecked_ref()));
+///
+/// // The call of `.forget()` is necessary. If we don't call this method, it will create a memory leak :(
+/// onclick.forget();
+///
+/// body().append_child(&button).unwrap();
+/// ```
+///
+/// [`FromWasmAbi`]: <https://docs.rs/wasm-bindgen/0.2.79/wasm_bindgen/convert/trait.FromWasmAbi.html>
 pub fn callback_with_arg<T, A>(handler: T) -> Closure<dyn Fn(A)>
 where
     T: Fn(A) + 'static,
@@ -353,6 +616,52 @@ where
     Closure::wrap(Box::new(handler) as Box<dyn Fn(A)>)
 }
 
+/// Javascript [`Callback`](https://developer.mozilla.org/en-US/docs/Glossary/Callback_function) which can only be called once
+///
+/// This function does the same thing as the [`callback`] function does, but it takes a Rust closure implementing `FnOnce` instead of `Fn`.
+/// So the closure can move its captured values out, without wrapping them in `Rc<Cell<Option<T>>>` first.
+///
+/// It uses the [`Closure::once`] method. The returned closure can only be called once: calling it a second time from javascript throws an error.
+/// So use it for handlers which are guaranteed to fire once, like a timeout or a `{ once: true }` event listener.
+///
+///
+/// # Arguments
+///
+/// * `handler` - A Rust closure which is called once
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use wasm_bindgen::JsCast;
+/// use weblog::console_log;
+/// use webru::callback_once;
+///
+/// let message = String::from("Hello world");
+///
+/// let callback = callback_once(move || {
+///     // `message` is moved out of the closure
+///     let message: String = message;
+///     console_log!(message);
+/// });
+///
+/// web_sys::window()
+///     .unwrap()
+///     .set_timeout_with_callback_and_timeout_and_arguments_0(callback.as_ref().unchecked_ref(), 1000)
+///     .unwrap();
+///
+/// // The call of `.forget()` is necessary. If we don't call this method, it will create a memory leak :(
+/// callback.forget();
+/// ```
+///
+/// [`Closure::once`]: <https://docs.rs/wasm-bindgen/0.2.79/wasm_bindgen/closure/struct.Closure.html#method.once>
+pub fn callback_once<T>(handler: T) -> Closure<dyn FnMut()>
+where
+    T: FnOnce() + 'static,
+{
+    Closure::once(handler)
+}
+
 /// Javascript [`document.createElement`](https://developer.mozilla.org/en-US/docs/Web/API/Document/createElement) method
 ///
 /// This function will create a new [`Element`](https://docs.rs/web-sys/0.3.56/web_sys/struct.Element.html) and return it.
@@ -374,7 +683,7 @@ where
 ///
 /// # Example
 ///
-/// ```
+/// ```no_run
 /// use webru::{body, create_element};
 ///
 /// // Create a <p> tag
@@ -399,6 +708,125 @@ pub fn create_element(element_name: &str) -> Element {
     document().create_element(element_name).unwrap()
 }
 
+/// Javascript [`document.createElement`](https://developer.mozilla.org/en-US/docs/Web/API/Document/createElement) method which returns an [`HtmlElement`](https://docs.rs/web-sys/0.3.56/web_sys/struct.HtmlElement.html)
+///
+/// This function does the same thing as the [`create_element`] function does, but it casts the element to `HtmlElement`.
+/// So you can use methods like `style()` or `set_onclick()` without writing `.dyn_ref::<HtmlElement>().unwrap().clone()` first.
+///
+/// Note that in an HTML document `createElement` always creates HTML elements, even for tag names like `svg`. To create SVG elements, use [`create_svg_element`] instead.
+///
+///
+/// # Arguments
+///
+/// * `tag` - A string that specifies the type of element to be created.
+///
+///
+/// # Panics
+///
+/// * This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+/// * This function will panic if the `tag` is not a valid tag name
+///
+/// * This function will panic if the created element is not an `HtmlElement`, which can happen in XML documents
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use wasm_bindgen::JsCast;
+/// use webru::{body, callback, create_html_element};
+///
+/// let button = create_html_element("button");
+///
+/// button.set_inner_html("click me");
+/// button.style().set_property("color", "red").unwrap();
+///
+/// let onclick = callback(|| {});
+/// button.set_onclick(Some(onclick.as_ref().unchecked_ref()));
+/// onclick.forget();
+///
+/// body().append_child(&button).unwrap();
+/// ```
+pub fn create_html_element(tag: &str) -> HtmlElement {
+    create_element(tag)
+        .dyn_into::<HtmlElement>()
+        .unwrap_or_else(|_| panic!("`<{}>` is not an HtmlElement", tag))
+}
+
+/// Javascript [`document.createElementNS`](https://developer.mozilla.org/en-US/docs/Web/API/Document/createElementNS) method
+///
+/// This function creates a new [`Element`](https://docs.rs/web-sys/0.3.56/web_sys/struct.Element.html) with the given namespace URI and returns it.
+///
+/// Elements which are not HTML, like SVG or MathML elements, must be created with their namespace. Otherwise the browser treats them as unknown HTML elements and doesn't render them.
+///
+/// Note that this function will create and return the element, not push to the DOM
+///
+///
+/// # Arguments
+///
+/// * `namespace` - The namespace URI of the element, like `http://www.w3.org/1998/Math/MathML`
+///
+/// * `tag` - A string that specifies the type of element to be created.
+///
+///
+/// # Panics
+///
+/// * This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+/// * This function will panic if the `tag` is not a valid tag name
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use webru::create_element_ns;
+///
+/// let fraction = create_element_ns("http://www.w3.org/1998/Math/MathML", "mfrac");
+/// ```
+pub fn create_element_ns(namespace: &str, tag: &str) -> Element {
+    document().create_element_ns(Some(namespace), tag).unwrap()
+}
+
+/// Create an SVG element
+///
+/// This function is equivalent to [`create_element_ns`] with the SVG namespace `http://www.w3.org/2000/svg`.
+///
+///
+/// # Arguments
+///
+/// * `tag` - The SVG tag name, like `svg`, `circle` or `path`
+///
+///
+/// # Panics
+///
+/// * This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+/// * This function will panic if the `tag` is not a valid tag name
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use webru::{body, create_svg_element};
+///
+/// let svg = create_svg_element("svg");
+/// svg.set_attribute("viewBox", "0 0 100 100").unwrap();
+///
+/// let circle = create_svg_element("circle");
+/// circle.set_attribute("cx", "50").unwrap();
+/// circle.set_attribute("cy", "50").unwrap();
+/// circle.set_attribute("r", "40").unwrap();
+///
+/// svg.append_child(&circle).unwrap();
+/// body().append_child(&svg).unwrap();
+/// ```
+pub fn create_svg_element(tag: &str) -> Element {
+    create_element_ns(SVG_NAMESPACE, tag)
+}
+
+/// The namespace URI of SVG elements
+const SVG_NAMESPACE: &str = "http://www.w3.org/2000/svg";
+
 /// Call a closure in a specific window width with media query
 ///
 /// # Panics
@@ -407,7 +835,7 @@ pub fn create_element(element_name: &str) -> Element {
 ///
 /// # Example
 ///
-/// ```rust
+/// ```no_run
 /// use webru::{body, create_element, media_query};
 ///
 /// let p = create_element("p");
@@ -0,0 +1,144 @@
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{window, History};
+
+/// Javascript [`history.pushState()`](https://developer.mozilla.org/en-US/docs/Web/API/History/pushState) method
+///
+/// This function adds a new entry to the browser's session history and changes the url in the address bar to `url`.
+///
+/// Note that it does *not* load the page at `url`, and it doesn't fire the `popstate` event. It's up to you (or your router) to render the content of the new url.
+///
+///
+/// # Arguments
+///
+/// * `url` - The new url. It can be relative to the current url, like `/users/42` or `?page=2`
+///
+///
+/// # Panics
+///
+/// * This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+/// * This function will panic if the `url` has another origin than the current page, like `https://example.org` on `https://example.com`
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use webru::{path_name, push_state};
+///
+/// push_state("/users/42");
+///
+/// assert_eq!(path_name(), "/users/42");
+/// ```
+pub fn push_state(url: &str) {
+    history()
+        .push_state_with_url(&JsValue::NULL, "", Some(url))
+        .unwrap();
+}
+
+/// Javascript [`history.replaceState()`](https://developer.mozilla.org/en-US/docs/Web/API/History/replaceState) method
+///
+/// This function does the same thing as the [`push_state`] function does, but it replaces the current entry of the session history instead of adding a new one.
+/// So the back button doesn't return to the previous url. It's useful for redirects and for updating query parameters like filters.
+///
+///
+/// # Arguments
+///
+/// * `url` - The new url. It can be relative to the current url, like `/users/42` or `?page=2`
+///
+///
+/// # Panics
+///
+/// * This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+/// * This function will panic if the `url` has another origin than the current page
+///
+pub fn replace_state(url: &str) {
+    history()
+        .replace_state_with_url(&JsValue::NULL, "", Some(url))
+        .unwrap();
+}
+
+/// Javascript [`history.back()`](https://developer.mozilla.org/en-US/docs/Web/API/History/back) method
+///
+/// This function goes to the previous page in the session history, just like the back button of the browser. It does nothing if there is no previous page.
+///
+/// The navigation is asynchronous: the url changes after this function returns.
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+pub fn go_back() {
+    history().back().unwrap();
+}
+
+/// Javascript [`history.forward()`](https://developer.mozilla.org/en-US/docs/Web/API/History/forward) method
+///
+/// This function goes to the next page in the session history, just like the forward button of the browser. It does nothing if there is no next page.
+///
+/// The navigation is asynchronous: the url changes after this function returns.
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+pub fn go_forward() {
+    history().forward().unwrap();
+}
+
+/// Listen to the [`popstate`](https://developer.mozilla.org/en-US/docs/Web/API/Window/popstate_event) event of the window
+///
+/// The `handler` is called when the user navigates the session history, for example with the back or forward button of the browser, or when [`go_back`] or [`go_forward`] is called.
+/// Read the new url with functions like [`path_name`](crate::path_name) inside the `handler` and render its content. Together with [`push_state`], this is all a simple router needs.
+///
+/// Note that [`push_state`] and [`replace_state`] don't fire this event.
+///
+/// Just like the [`callback`](crate::callback) function, it returns the [`Closure`] of the listener. You must keep it alive as long as the listener is registered:
+/// if the `Closure` is dropped, the listener stays registered and throws an error on the next navigation. Usually you want to call `.forget()` on it, which leaks it for the lifetime of the page.
+///
+///
+/// # Arguments
+///
+/// * `handler` - The Rust closure to call on every navigation
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use weblog::console_log;
+/// use webru::{on_popstate, path_name};
+///
+/// let listener = on_popstate(|| {
+///     console_log!("Navigated to ", path_name());
+/// });
+///
+/// // The call of `.forget()` is necessary. If we don't call this method, the listener will throw an error
+/// listener.forget();
+/// ```
+///
+/// [`Closure`]: <https://docs.rs/wasm-bindgen/0.2.79/wasm_bindgen/closure/struct.Closure.html>
+pub fn on_popstate<T>(handler: T) -> Closure<dyn Fn()>
+where
+    T: Fn() + 'static,
+{
+    let closure = Closure::wrap(Box::new(handler) as Box<dyn Fn()>);
+
+    window()
+        .unwrap()
+        .add_event_listener_with_callback("popstate", closure.as_ref().unchecked_ref())
+        .unwrap();
+
+    closure
+}
+
+fn history() -> History {
+    window().unwrap().history().unwrap()
+}
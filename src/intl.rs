@@ -0,0 +1,232 @@
+use js_sys::{Array, Intl, Object, Reflect};
+use wasm_bindgen::JsValue;
+use web_sys::window;
+
+use crate::document;
+
+/// Format how long ago something happened, like `3 minutes ago`
+///
+/// This function uses javascript's [`Intl.RelativeTimeFormat`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/RelativeTimeFormat) API,
+/// so the text is translated to the language of the document (the `lang` attribute of the `<html>` element, or the browser's language if it isn't set).
+///
+/// The unit is chosen from the size of `seconds_ago`:
+///
+/// * Less than a minute is shown in seconds
+///
+/// * Less than an hour is shown in minutes
+///
+/// * Less than a day is shown in hours
+///
+/// * Everything else is shown in days
+///
+/// The value is rounded down, so 90 seconds ago is `1 minute ago`. A negative `seconds_ago` is in the future, like `in 5 minutes`.
+///
+///
+/// # Arguments
+///
+/// * `seconds_ago` - How many seconds ago the thing happened
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use webru::relative_time;
+///
+/// // with `<html lang="en">`
+/// assert_eq!(relative_time(180), "3 minutes ago");
+/// assert_eq!(relative_time(7200), "2 hours ago");
+/// ```
+pub fn relative_time(seconds_ago: i64) -> String {
+    const MINUTE: i64 = 60;
+    const HOUR: i64 = 60 * MINUTE;
+    const DAY: i64 = 24 * HOUR;
+
+    let (value, unit) = match seconds_ago.abs() {
+        seconds if seconds < MINUTE => (seconds_ago, "second"),
+        seconds if seconds < HOUR => (seconds_ago / MINUTE, "minute"),
+        seconds if seconds < DAY => (seconds_ago / HOUR, "hour"),
+        _ => (seconds_ago / DAY, "day"),
+    };
+
+    let locales = match document_language() {
+        Some(lang) => Array::of1(&lang.into()),
+        None => Array::new(),
+    };
+
+    let formatter = Intl::RelativeTimeFormat::new(&locales, &Object::new());
+
+    // `-0.0` for `0`, so it's formatted as `0 seconds ago` instead of `in 0 seconds`
+    formatter.format(-(value as f64), unit).into()
+}
+
+/// The `lang` attribute of the `<html>` element
+fn document_language() -> Option<String> {
+    document()
+        .document_element()
+        .and_then(|root| root.get_attribute("lang"))
+        .filter(|lang| !lang.is_empty())
+}
+
+/// Format a number with the conventions of a locale
+///
+/// This function uses javascript's [`Intl.NumberFormat`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/NumberFormat) API,
+/// so the thousands separator and the decimal separator match the `locale`. At most 3 decimals are shown.
+///
+///
+/// # Arguments
+///
+/// * `n` - The number to format
+///
+/// * `locale` - A [BCP 47 language tag](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl#locales_argument) such as `en-US` or `de-DE`
+///
+///
+/// # Panics
+///
+/// * This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+/// * This function will panic if the `locale` is not a valid language tag
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use webru::format_number;
+///
+/// assert_eq!(format_number(1234.5, "en-US"), "1,234.5");
+/// assert_eq!(format_number(1234.5, "de-DE"), "1.234,5");
+/// ```
+pub fn format_number(n: f64, locale: &str) -> String {
+    let formatter = Intl::NumberFormat::new(&Array::of1(&locale.into()), &Object::new());
+
+    format_number_with(&formatter, n)
+}
+
+/// Format an amount of money with the conventions of a locale
+///
+/// This function uses javascript's [`Intl.NumberFormat`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/NumberFormat) API with the `currency` style,
+/// so the currency symbol, its position and the number of decimals match the `locale` and the `currency`.
+///
+///
+/// # Arguments
+///
+/// * `n` - The amount to format
+///
+/// * `locale` - A [BCP 47 language tag](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl#locales_argument) such as `en-US` or `de-DE`
+///
+/// * `currency` - An [ISO 4217 currency code](https://en.wikipedia.org/wiki/ISO_4217#List_of_ISO_4217_currency_codes) such as `USD` or `EUR`
+///
+///
+/// # Panics
+///
+/// * This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+/// * This function will panic if the `locale` or the `currency` is not valid
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use webru::format_currency;
+///
+/// assert_eq!(format_currency(1234.5, "en-US", "USD"), "$1,234.50");
+/// ```
+pub fn format_currency(n: f64, locale: &str, currency: &str) -> String {
+    let options = Object::new();
+    Reflect::set(&options, &"style".into(), &"currency".into()).unwrap();
+    Reflect::set(&options, &"currency".into(), &currency.into()).unwrap();
+
+    let formatter = Intl::NumberFormat::new(&Array::of1(&locale.into()), &options);
+
+    format_number_with(&formatter, n)
+}
+
+/// Get the time zone of the user
+///
+/// This function is equivalent to javascript's [`Intl.DateTimeFormat().resolvedOptions().timeZone`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DateTimeFormat/resolvedOptions) expression.
+/// It returns an [IANA time zone name](https://www.iana.org/time-zones) such as `Europe/Berlin` or `America/New_York`.
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use weblog::console_log;
+/// use webru::timezone;
+///
+/// console_log!("Your time zone is ", timezone());
+/// ```
+pub fn timezone() -> String {
+    let options = Intl::DateTimeFormat::new(&Array::new(), &Object::new()).resolved_options();
+
+    Reflect::get(&options, &"timeZone".into())
+        .unwrap()
+        .as_string()
+        .unwrap()
+}
+
+/// Get the preferred language of the user
+///
+/// This function is equivalent to javascript's [`navigator.language`](https://developer.mozilla.org/en-US/docs/Web/API/Navigator/language) property.
+/// It returns a [BCP 47 language tag](https://developer.mozilla.org/en-US/docs/Glossary/BCP_47_language_tag) such as `en-US` or `de`, which is usually the language of the browser's user interface.
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use webru::language;
+///
+/// let greeting = match language().as_deref() {
+///     Some(lang) if lang.starts_with("de") => "Hallo",
+///     _ => "Hello",
+/// };
+/// ```
+pub fn language() -> Option<String> {
+    window().unwrap().navigator().language()
+}
+
+/// Get the languages of the user, ordered by preference
+///
+/// This function converts javascript's [`navigator.languages`](https://developer.mozilla.org/en-US/docs/Web/API/Navigator/languages) array into a `Vec` of [BCP 47 language tags](https://developer.mozilla.org/en-US/docs/Glossary/BCP_47_language_tag) such as `["en-US", "en", "de"]`.
+/// The first one is usually the same as [`language`].
+///
+/// It returns an empty `Vec` if the browser doesn't expose the list.
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+pub fn languages() -> Vec<String> {
+    window()
+        .unwrap()
+        .navigator()
+        .languages()
+        .iter()
+        .filter_map(|lang| lang.as_string())
+        .collect()
+}
+
+/// Call the `format` method of an `Intl.NumberFormat`
+fn format_number_with(formatter: &Intl::NumberFormat, n: f64) -> String {
+    // `format` is a getter returning a function bound to the formatter
+    formatter
+        .format()
+        .call1(&JsValue::UNDEFINED, &n.into())
+        .unwrap()
+        .as_string()
+        .unwrap()
+}
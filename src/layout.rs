@@ -0,0 +1,192 @@
+use wasm_bindgen::JsCast;
+use web_sys::{Element, HtmlElement};
+
+use crate::style::computed_property;
+
+/// Check if an element is actually visible on the page
+///
+/// An element is considered hidden if:
+///
+/// * It (or one of its ancestors) has `display: none`, or it isn't inserted into the DOM. This is detected with the [`offsetParent`](https://developer.mozilla.org/en-US/docs/Web/API/HTMLElement/offsetParent) property
+///
+/// * It has a computed `visibility` of `hidden` or `collapse`
+///
+/// * It has zero width and zero height
+///
+/// Elements with `position: fixed` always have a `null` offsetParent, so for them only the size and the visibility are checked.
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use wasm_bindgen::JsCast;
+/// use web_sys::HtmlElement;
+/// use webru::{body, create_element, is_visible_element};
+///
+/// let div: HtmlElement = create_element("div")
+///     .dyn_ref::<HtmlElement>()
+///     .unwrap()
+///     .clone();
+///
+/// div.set_inner_html("Hello world");
+///
+/// // not inserted into the DOM yet
+/// assert!(!is_visible_element(&div));
+///
+/// body().append_child(&div).unwrap();
+///
+/// assert!(is_visible_element(&div));
+/// ```
+pub fn is_visible_element(el: &HtmlElement) -> bool {
+    let position = computed_property(el, "position").unwrap_or_default();
+
+    if el.offset_parent().is_none() && position != "fixed" {
+        return false;
+    }
+
+    if el.offset_width() == 0 && el.offset_height() == 0 {
+        return false;
+    }
+
+    let visibility = computed_property(el, "visibility").unwrap_or_default();
+
+    visibility != "hidden" && visibility != "collapse"
+}
+
+/// Get the size of an element's content box including its padding
+///
+/// This function returns the [`clientWidth`](https://developer.mozilla.org/en-US/docs/Web/API/Element/clientWidth) and [`clientHeight`](https://developer.mozilla.org/en-US/docs/Web/API/Element/clientHeight) properties as `(width, height)`.
+/// Borders, margins and scrollbars are not included.
+///
+/// Elements without a CSS layout box (for example inline elements or elements with `display: none`) have a size of `(0.0, 0.0)`
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+pub fn content_size(el: &Element) -> (f64, f64) {
+    (el.client_width() as f64, el.client_height() as f64)
+}
+
+/// Get the size of an element's content including the content which is not visible due to overflow
+///
+/// This function returns the [`scrollWidth`](https://developer.mozilla.org/en-US/docs/Web/API/Element/scrollWidth) and [`scrollHeight`](https://developer.mozilla.org/en-US/docs/Web/API/Element/scrollHeight) properties as `(width, height)`.
+///
+/// If the element doesn't overflow, this is the same as [`content_size`]
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+pub fn scroll_size(el: &Element) -> (f64, f64) {
+    (el.scroll_width() as f64, el.scroll_height() as f64)
+}
+
+/// Get the position of an element relative to the top-left corner of the document
+///
+/// This function walks the [`offsetParent`](https://developer.mozilla.org/en-US/docs/Web/API/HTMLElement/offsetParent) chain and sums the [`offsetLeft`](https://developer.mozilla.org/en-US/docs/Web/API/HTMLElement/offsetLeft) and [`offsetTop`](https://developer.mozilla.org/en-US/docs/Web/API/HTMLElement/offsetTop) properties.
+///
+/// It returns `(x, y)`. Unlike [`getBoundingClientRect()`](https://developer.mozilla.org/en-US/docs/Web/API/Element/getBoundingClientRect), the result doesn't change when the page is scrolled.
+///
+/// Borders of the offset parents and CSS transforms are not taken into account.
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+pub fn document_offset(el: &HtmlElement) -> (f64, f64) {
+    let mut x = 0.0;
+    let mut y = 0.0;
+    let mut current = Some(el.clone());
+
+    while let Some(element) = current {
+        x += element.offset_left() as f64;
+        y += element.offset_top() as f64;
+
+        current = element
+            .offset_parent()
+            .and_then(|parent| parent.dyn_into::<HtmlElement>().ok());
+    }
+
+    (x, y)
+}
+
+/// Get the center of an element relative to the viewport
+///
+/// The center is computed from javascript's [`getBoundingClientRect()`](https://developer.mozilla.org/en-US/docs/Web/API/Element/getBoundingClientRect) method as `(x + width / 2, y + height / 2)`.
+/// It's useful for positioning tooltips or spawning effects at an element.
+///
+/// As it's relative to the viewport, the result changes when the page is scrolled.
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use webru::{center_point, get_element_by_id};
+///
+/// let avatar = get_element_by_id("avatar").unwrap();
+///
+/// let (x, y) = center_point(&avatar);
+/// ```
+pub fn center_point(el: &Element) -> (f64, f64) {
+    let rect = el.get_bounding_client_rect();
+
+    (
+        rect.x() + rect.width() / 2.0,
+        rect.y() + rect.height() / 2.0,
+    )
+}
+
+/// Get the position of an element relative to one of its ancestors
+///
+/// This function subtracts the top-left corner of the `ancestor`'s [`getBoundingClientRect()`](https://developer.mozilla.org/en-US/docs/Web/API/Element/getBoundingClientRect) from the element's one, and returns `(x, y)`.
+/// It's useful for positioning a tooltip or a popover inside a container with `position: relative`.
+///
+/// The result is measured from the border edge of the `ancestor`, so its borders are included.
+///
+/// The positions are read as they are on the screen right now. If a container between the two elements (or the `ancestor` itself) is scrolled,
+/// the scroll offset is not added back, so the result is the visible offset and not the offset in the scrollable content.
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use wasm_bindgen::JsCast;
+/// use web_sys::HtmlElement;
+/// use webru::{get_element_by_id, rect_relative_to, set_styles};
+///
+/// let container = get_element_by_id("chart").unwrap();
+/// let point = get_element_by_id("point-12").unwrap();
+/// let tooltip: HtmlElement = get_element_by_id("tooltip").unwrap().dyn_into().unwrap();
+///
+/// let (x, y) = rect_relative_to(&point, &container);
+///
+/// set_styles(
+///     &tooltip,
+///     &[("left", &format!("{}px", x)), ("top", &format!("{}px", y - 30.0))],
+/// );
+/// ```
+pub fn rect_relative_to(el: &Element, ancestor: &Element) -> (f64, f64) {
+    let rect = el.get_bounding_client_rect();
+    let ancestor_rect = ancestor.get_bounding_client_rect();
+
+    (rect.x() - ancestor_rect.x(), rect.y() - ancestor_rect.y())
+}
@@ -10,7 +10,7 @@
 //!
 //! ### Using some global objects and functions
 //!
-//! ```rust
+//! ```no_run
 //! use webru::{alert, body, create_element, prompt};
 //! use web_sys::{Element, HtmlElement};
 //!
@@ -46,12 +46,12 @@
 //!
 //! ### Using `setTimeout` and `setInterval`
 //!
-//! ```rust
+//! ```no_run
 //! use std::cell::Cell;
 //! use weblog::console_log;
 //!
 //! {
-//!     use crate::{clear_interval, set_interval, set_timeout};
+//!     use webru::{clear_interval, set_interval, set_timeout};
 //!
 //!     let number = Cell::new(0);
 //!
@@ -81,7 +81,7 @@
 //! }
 //! // Alternatively, you can use `Timeout` and `Interval` structs instead of `set_timeout` and `set_interval`
 //! {
-//!     use crate::{Interval, Timeout};
+//!     use webru::{Interval, Timeout};
 //!
 //!     let number: Cell<i32> = Cell::new(0);
 //!
@@ -105,13 +105,14 @@
 //!             interval.stop();
 //!         },
 //!         10000, // 10 seconds
-//!     );
+//!     )
+//!     .forget();
 //! }
 //! ```
 //!
 //! ### Using selectors
 //!
-//! ```rust
+//! ```no_run
 //! use webru::{body, create_element, get_element_by_id, get_elements_by_classname_inside_vec};
 //! use web_sys::{Element, HtmlElement};
 //!
@@ -164,7 +165,7 @@
 //!
 //! ### Using javascript callback
 //!
-//! ```rust
+//! ```no_run
 //! use webru::{body, callback, create_element};
 //! use std::cell::Cell;
 //! use wasm_bindgen::JsCast;
@@ -176,7 +177,8 @@
 //! // "onclick" event handler for the <button> tag
 //! let onclick = callback(move || {
 //!     count.set(count.get() + 1);
-//!     console_log!(format!("Button clicked {} times", count.get()));
+//!     let msg = format!("Button clicked {} times", count.get());
+//!     console_log!(msg);
 //! });
 //!
 //! // get the body object
@@ -200,7 +202,7 @@
 //!
 //! ### Using media query
 //!
-//! ```rust
+//! ```no_run
 //! use webru::media_query;
 //! use weblog::console_log;
 //!
@@ -226,13 +228,71 @@
 //! Your valuable PRs and Issues are welcome. Note that all the contribution submitted by you, shall be licensed as MIT or APACHE 2.0 at your choice, without any additional terms or conditions.
 //!
 
+mod classlist;
+mod clipboard;
+mod cookie;
+mod crypto;
+mod dom;
+mod download;
+mod events;
+mod fetch;
+mod file;
+mod focus;
+mod form;
+mod geo;
 mod global;
+mod history;
+mod intl;
+mod layout;
+mod lifecycle;
+mod loader;
+mod media;
+mod message;
+mod network;
+mod observer;
+mod perf;
+mod raf;
+mod scroll;
+mod selection;
 mod selectors;
+mod storage;
+mod style;
 // mod temp;
 mod timer;
+mod util;
+mod worker;
 
 // exporting functions
 
+pub use classlist::*;
+pub use clipboard::*;
+pub use cookie::*;
+pub use crypto::*;
+pub use dom::*;
+pub use download::*;
+pub use events::*;
+pub use fetch::*;
+pub use file::*;
+pub use focus::*;
+pub use form::*;
+pub use geo::*;
 pub use global::*;
+pub use history::*;
+pub use intl::*;
+pub use layout::*;
+pub use lifecycle::*;
+pub use loader::*;
+pub use media::*;
+pub use message::*;
+pub use network::*;
+pub use observer::*;
+pub use perf::*;
+pub use raf::*;
+pub use scroll::*;
+pub use selection::*;
 pub use selectors::*;
+pub use storage::*;
+pub use style::*;
 pub use timer::*;
+pub use util::*;
+pub use worker::*;
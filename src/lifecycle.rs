@@ -0,0 +1,144 @@
+use js_sys::Promise;
+use wasm_bindgen::JsValue;
+use wasm_bindgen_futures::JsFuture;
+use web_sys::VisibilityState;
+
+use crate::document;
+use crate::events::{listen, EventListenerHandle};
+
+/// Get the loading state of the document
+///
+/// This function is equivalent to javascript's [`document.readyState`](https://developer.mozilla.org/en-US/docs/Web/API/Document/readyState) property. It returns one of:
+///
+/// * `"loading"` - The document is still being parsed
+///
+/// * `"interactive"` - The document is parsed and the DOM can be used, but images, stylesheets and frames may still be loading
+///
+/// * `"complete"` - The document and all of its resources are loaded
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+pub fn ready_state() -> String {
+    document().ready_state()
+}
+
+/// Wait until the DOM is ready
+///
+/// The returned future resolves on the [`DOMContentLoaded`](https://developer.mozilla.org/en-US/docs/Web/API/Document/DOMContentLoaded_event) event of the document.
+/// If the document is already `interactive` or `complete` (see [`ready_state`]), the event has already fired, so the future resolves immediately instead of waiting forever.
+///
+/// This gives async apps a single point to await at startup, no matter when the wasm module is loaded.
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use webru::{get_element_by_id, until_ready};
+///
+/// wasm_bindgen_futures::spawn_local(async {
+///     until_ready().await;
+///
+///     // the whole DOM is available now
+///     let app = get_element_by_id("app").unwrap();
+/// });
+/// ```
+pub async fn until_ready() {
+    if ready_state() != "loading" {
+        return;
+    }
+
+    let mut listener = None;
+
+    let promise = Promise::new(&mut |resolve, _reject| {
+        listener = Some(listen(&document(), "DOMContentLoaded", move |_| {
+            resolve.call0(&JsValue::UNDEFINED).unwrap();
+        }));
+    });
+
+    JsFuture::from(promise).await.unwrap();
+
+    drop(listener);
+}
+
+/// Get the visibility state of the page
+///
+/// This function is equivalent to javascript's [`document.visibilityState`](https://developer.mozilla.org/en-US/docs/Web/API/Document/visibilityState) property.
+/// It returns `"visible"` if the page is at least partially visible, and `"hidden"` if the tab is in the background, the window is minimized or the screen is locked.
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+pub fn visibility_state() -> String {
+    match document().visibility_state() {
+        VisibilityState::Hidden => "hidden",
+        // including the `prerender` state of older browsers
+        _ => "visible",
+    }
+    .to_string()
+}
+
+/// Check if the page is hidden
+///
+/// This function is equivalent to javascript's [`document.hidden`](https://developer.mozilla.org/en-US/docs/Web/API/Document/hidden) property. See [`visibility_state`] for when a page is hidden.
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+pub fn is_hidden() -> bool {
+    document().hidden()
+}
+
+/// Listen to the [`visibilitychange`](https://developer.mozilla.org/en-US/docs/Web/API/Document/visibilitychange_event) event of the document
+///
+/// The `handler` is called with [`is_hidden`] whenever the page becomes hidden or visible again.
+///
+/// This pairs well with an [`Interval`](crate::Interval): stop polling or animating while the tab is in the background to save battery and bandwidth, and start again when the user comes back.
+///
+/// The listener is removed when the returned [`EventListenerHandle`] is dropped.
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use std::cell::RefCell;
+/// use weblog::console_log;
+/// use webru::{on_visibility_change, Interval};
+///
+/// let poll = || console_log!("Checking for new messages");
+/// let polling = RefCell::new(Some(Interval::start(poll, 5000)));
+///
+/// on_visibility_change(move |hidden| {
+///     // dropping the interval stops it
+///     *polling.borrow_mut() = if hidden {
+///         None
+///     } else {
+///         Some(Interval::start(poll, 5000))
+///     };
+/// })
+/// .forget();
+/// ```
+pub fn on_visibility_change<T>(handler: T) -> EventListenerHandle
+where
+    T: Fn(bool) + 'static,
+{
+    listen(&document(), "visibilitychange", move |_| {
+        handler(is_hidden())
+    })
+}
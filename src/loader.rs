@@ -0,0 +1,64 @@
+use js_sys::Promise;
+use wasm_bindgen::JsValue;
+use wasm_bindgen_futures::JsFuture;
+
+use crate::document;
+use crate::events::listen;
+
+/// Load an external script
+///
+/// This function creates a `<script src="...">` element and appends it to the `<head>`. The returned future resolves when the script is loaded and executed,
+/// so you can use the globals it defines right after `.await`. It's useful for lazy-loading third party SDKs like maps or payment providers.
+///
+/// It returns `Err` if the script can't be loaded, for example because of a network error, a `404` or a [Content Security Policy](https://developer.mozilla.org/en-US/docs/Web/HTTP/CSP).
+///
+/// Every call appends a new `<script>`, even if the same `src` was loaded before. It's up to the caller to avoid loading a script twice.
+///
+///
+/// # Panics
+///
+/// * This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+/// * This function will panic if the document has no `<head>`
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use weblog::console_log;
+/// use webru::load_script;
+///
+/// wasm_bindgen_futures::spawn_local(async {
+///     match load_script("https://example.com/sdk.js").await {
+///         Ok(()) => console_log!("The SDK is ready"),
+///         Err(error) => console_log!("Couldn't load the SDK: ", error),
+///     }
+/// });
+/// ```
+pub async fn load_script(src: &str) -> Result<(), JsValue> {
+    let script = document().create_element("script").unwrap();
+    script.set_attribute("src", src).unwrap();
+
+    let mut listeners = None;
+
+    let promise = Promise::new(&mut |resolve, reject| {
+        let on_load = listen(&script, "load", move |_| {
+            resolve.call0(&JsValue::UNDEFINED).unwrap();
+        });
+
+        let error = JsValue::from_str(&format!("failed to load the script {}", src));
+        let on_error = listen(&script, "error", move |_| {
+            reject.call1(&JsValue::UNDEFINED, &error).unwrap();
+        });
+
+        listeners = Some(on_load.join(on_error));
+    });
+
+    document().head().unwrap().append_child(&script).unwrap();
+
+    let result = JsFuture::from(promise).await;
+
+    drop(listeners);
+
+    result.map(|_| ())
+}
@@ -0,0 +1,98 @@
+use js_sys::Promise;
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{window, HtmlImageElement, MediaQueryListEvent};
+
+use crate::events::{listen, EventListenerHandle};
+
+/// Load an image and wait until it's ready
+///
+/// This function creates a new javascript [`Image`](https://developer.mozilla.org/en-US/docs/Web/API/HTMLImageElement/Image), sets its `src` and resolves when the image's `load` event fires.
+/// It's useful for preloading images before showing them.
+///
+/// It returns `Err` with the `error` event if the image couldn't be loaded
+///
+///
+/// # Arguments
+///
+/// * `src` - The url of the image
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use webru::{body, load_image};
+///
+/// wasm_bindgen_futures::spawn_local(async {
+///     let image = load_image("/images/banner.png").await.unwrap();
+///
+///     // the image is fully loaded, so there will be no flickering
+///     body().append_child(&image).unwrap();
+/// });
+/// ```
+pub async fn load_image(src: &str) -> Result<HtmlImageElement, JsValue> {
+    let image = HtmlImageElement::new().unwrap();
+
+    let promise = Promise::new(&mut |resolve, reject| {
+        image.set_onload(Some(&resolve));
+        image.set_onerror(Some(&reject));
+    });
+
+    image.set_src(src);
+
+    let result = JsFuture::from(promise).await;
+
+    image.set_onload(None);
+    image.set_onerror(None);
+
+    result.map(|_| image)
+}
+
+/// Listen to the changes of the user's preferred color scheme
+///
+/// The `handler` is called with `true` when the operating system (or the browser) switches to a dark theme, and with `false` when it switches to a light theme.
+/// It listens to the `change` event of the [`prefers-color-scheme: dark`](https://developer.mozilla.org/en-US/docs/Web/CSS/@media/prefers-color-scheme) media query.
+///
+/// The `handler` is not called for the current preference. Check `window.matchMedia("(prefers-color-scheme: dark)").matches` for that.
+///
+/// The listener is removed when the returned [`EventListenerHandle`] is dropped.
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use webru::{body, on_color_scheme_change};
+///
+/// let listener = on_color_scheme_change(|dark| {
+///     body().set_class_name(if dark { "dark" } else { "light" });
+/// });
+///
+/// // keep listening for the lifetime of the page
+/// listener.forget();
+/// ```
+pub fn on_color_scheme_change<T>(mut handler: T) -> EventListenerHandle
+where
+    T: FnMut(bool) + 'static,
+{
+    let query = window()
+        .unwrap()
+        .match_media("(prefers-color-scheme: dark)")
+        .unwrap()
+        .unwrap();
+
+    listen(&query, "change", move |event| {
+        if let Some(event) = event.dyn_ref::<MediaQueryListEvent>() {
+            handler(event.matches());
+        }
+    })
+}
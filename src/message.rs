@@ -0,0 +1,91 @@
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{window, MessageEvent, Window};
+
+/// Send a message to another window
+///
+/// This function is equivalent to javascript's [`targetWindow.postMessage(msg, targetOrigin)`](https://developer.mozilla.org/en-US/docs/Web/API/Window/postMessage) method.
+/// It's the way to communicate with an `<iframe>`, a popup or the page which embeds the current page.
+///
+/// The message is only delivered if the origin of the `target` window matches `target_origin`. Always pass the exact origin you expect, like `https://example.com`,
+/// when the message contains anything private. `"*"` delivers the message to any origin.
+///
+/// It returns `Err` if `target_origin` is not a valid origin.
+///
+///
+/// # Arguments
+///
+/// * `target` - The window to send the message to, for example [`HtmlIFrameElement::content_window()`](https://docs.rs/web-sys/0.3.56/web_sys/struct.HtmlIFrameElement.html#method.content_window) or [`Window::parent()`](https://docs.rs/web-sys/0.3.56/web_sys/struct.Window.html#method.parent)
+///
+/// * `msg` - The message
+///
+/// * `target_origin` - The origin the `target` window must have to receive the message
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use webru::post_message_to;
+///
+/// let parent = web_sys::window().unwrap().parent().unwrap().unwrap();
+///
+/// post_message_to(&parent, "widget-ready", "https://example.com").unwrap();
+/// ```
+pub fn post_message_to(target: &Window, msg: &str, target_origin: &str) -> Result<(), JsValue> {
+    target.post_message(&JsValue::from_str(msg), target_origin)
+}
+
+/// Listen to the [`message`](https://developer.mozilla.org/en-US/docs/Web/API/Window/message_event) event of the current window
+///
+/// The `handler` is called with `(data, origin)` for every string message sent to this window with `postMessage`. Messages with other kinds of data are ignored.
+///
+/// **Any window can send messages to this window.** Never trust a message without checking that the `origin` is the one you expect, for example `https://example.com`.
+///
+/// Just like the [`callback`](crate::callback) function, it returns the [`Closure`] of the listener. You must keep it alive as long as the listener is registered:
+/// if the `Closure` is dropped, the listener stays registered and throws an error when the event fires. Usually you want to call `.forget()` on it.
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use weblog::console_log;
+/// use webru::on_window_message;
+///
+/// on_window_message(|data, origin| {
+///     if origin != "https://example.com" {
+///         return;
+///     }
+///
+///     console_log!("Message from the embedding page: ", data);
+/// })
+/// .forget();
+/// ```
+///
+/// [`Closure`]: <https://docs.rs/wasm-bindgen/0.2.79/wasm_bindgen/closure/struct.Closure.html>
+pub fn on_window_message<T>(handler: T) -> Closure<dyn Fn(MessageEvent)>
+where
+    T: Fn(String, String) + 'static,
+{
+    let closure = Closure::wrap(Box::new(move |event: MessageEvent| {
+        if let Some(data) = event.data().as_string() {
+            handler(data, event.origin());
+        }
+    }) as Box<dyn Fn(MessageEvent)>);
+
+    window()
+        .unwrap()
+        .add_event_listener_with_callback("message", closure.as_ref().unchecked_ref())
+        .unwrap();
+
+    closure
+}
@@ -0,0 +1,107 @@
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::JsCast;
+use web_sys::window;
+
+/// Check if the browser is online
+///
+/// This function is equivalent to javascript's [`navigator.onLine`](https://developer.mozilla.org/en-US/docs/Web/API/Navigator/onLine) property.
+///
+/// Note that `false` reliably means that there is no network, but `true` only means that the device is connected to a network.
+/// It can still be `true` when the network has no access to the internet, for example a LAN without a router.
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use webru::{alert, is_online};
+///
+/// if !is_online() {
+///     alert("You are offline. Your changes will be saved when you are back online.");
+/// }
+/// ```
+pub fn is_online() -> bool {
+    window().unwrap().navigator().on_line()
+}
+
+/// Listen to the [`online`](https://developer.mozilla.org/en-US/docs/Web/API/Window/online_event) event of the window
+///
+/// The `handler` is called when the browser gets access to the network again. See [`is_online`] for what "online" means.
+///
+/// Just like the [`callback`](crate::callback) function, it returns the [`Closure`] of the listener. You must keep it alive as long as the listener is registered:
+/// if the `Closure` is dropped, the listener stays registered and throws an error when the event fires. Usually you want to call `.forget()` on it.
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use webru::{get_element_by_id, on_offline, on_online};
+///
+/// // show a banner while the browser is offline
+/// on_offline(|| {
+///     get_element_by_id("offline-banner")
+///         .unwrap()
+///         .remove_attribute("hidden")
+///         .unwrap();
+/// })
+/// .forget();
+///
+/// on_online(|| {
+///     get_element_by_id("offline-banner")
+///         .unwrap()
+///         .set_attribute("hidden", "")
+///         .unwrap();
+/// })
+/// .forget();
+/// ```
+///
+/// [`Closure`]: <https://docs.rs/wasm-bindgen/0.2.79/wasm_bindgen/closure/struct.Closure.html>
+pub fn on_online<T>(handler: T) -> Closure<dyn Fn()>
+where
+    T: Fn() + 'static,
+{
+    window_listener("online", handler)
+}
+
+/// Listen to the [`offline`](https://developer.mozilla.org/en-US/docs/Web/API/Window/offline_event) event of the window
+///
+/// The `handler` is called when the browser loses access to the network.
+///
+/// Just like [`on_online`], it returns the [`Closure`] of the listener, which must be kept alive (usually with `.forget()`).
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+/// [`Closure`]: <https://docs.rs/wasm-bindgen/0.2.79/wasm_bindgen/closure/struct.Closure.html>
+pub fn on_offline<T>(handler: T) -> Closure<dyn Fn()>
+where
+    T: Fn() + 'static,
+{
+    window_listener("offline", handler)
+}
+
+/// Register `handler` on the window for `event` and return its `Closure`
+fn window_listener<T>(event: &str, handler: T) -> Closure<dyn Fn()>
+where
+    T: Fn() + 'static,
+{
+    let closure = Closure::wrap(Box::new(handler) as Box<dyn Fn()>);
+
+    window()
+        .unwrap()
+        .add_event_listener_with_callback(event, closure.as_ref().unchecked_ref())
+        .unwrap();
+
+    closure
+}
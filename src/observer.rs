@@ -0,0 +1,91 @@
+use js_sys::Array;
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::JsCast;
+use web_sys::{Element, MutationObserver, MutationObserverInit};
+
+/// A handle to a [`MutationObserver`](https://developer.mozilla.org/en-US/docs/Web/API/MutationObserver)
+///
+/// The handle owns the Rust closure of the observer. When the handle is dropped (or when [`disconnect`](MutationObserverHandle::disconnect) is called), the observer is disconnected and the closure is freed.
+pub struct MutationObserverHandle {
+    observer: MutationObserver,
+    _closure: Closure<dyn FnMut(Array, MutationObserver)>,
+}
+
+impl MutationObserverHandle {
+    /// Stops observing.
+    ///
+    /// This is the same as dropping the handle.
+    ///
+    pub fn disconnect(self) {
+        drop(self);
+    }
+}
+
+impl Drop for MutationObserverHandle {
+    fn drop(&mut self) {
+        self.observer.disconnect();
+    }
+}
+
+/// Watch one attribute of an element
+///
+/// The `handler` is called with the current value of the attribute whenever it's set or removed (`None` means the attribute was removed).
+/// This is a focused version of javascript's [`MutationObserver`](https://developer.mozilla.org/en-US/docs/Web/API/MutationObserver), which would otherwise need a lot of wiring for a single attribute.
+///
+/// Mutation observers are called asynchronously (in a microtask), so several changes made in the same task are reported once, with the latest value.
+///
+/// The observer is disconnected when the returned [`MutationObserverHandle`] is dropped.
+///
+///
+/// # Arguments
+///
+/// * `el` - The element to watch
+///
+/// * `attr` - The name of the attribute to watch, such as `class` or `aria-expanded`
+///
+/// * `handler` - The Rust closure to call with the new value of the attribute
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use weblog::console_log;
+/// use webru::{get_element_by_id, on_attribute_change};
+///
+/// let menu = get_element_by_id("menu").unwrap();
+///
+/// let observer = on_attribute_change(&menu, "aria-expanded", |value: Option<String>| {
+///     console_log!("The menu is expanded: ", value == Some("true".to_string()));
+/// });
+/// ```
+pub fn on_attribute_change<T>(el: &Element, attr: &str, handler: T) -> MutationObserverHandle
+where
+    T: FnMut(Option<String>) + 'static,
+{
+    let mut handler = handler;
+
+    let closure = Closure::wrap(Box::new({
+        let el = el.clone();
+        let attr = attr.to_string();
+
+        move |_records: Array, _observer: MutationObserver| handler(el.get_attribute(&attr))
+    }) as Box<dyn FnMut(Array, MutationObserver)>);
+
+    let observer = MutationObserver::new(closure.as_ref().unchecked_ref()).unwrap();
+
+    let options = MutationObserverInit::new();
+    options.set_attributes(true);
+    options.set_attribute_filter(&Array::of1(&attr.into()));
+
+    observer.observe_with_options(el, &options).unwrap();
+
+    MutationObserverHandle {
+        observer,
+        _closure: closure,
+    }
+}
@@ -0,0 +1,58 @@
+use web_sys::HtmlElement;
+
+/// Hide an element while mutating it, so the browser lays it out only once
+///
+/// This function sets the inline `display` of the element to `none`, runs `f`, and then restores the previous inline `display` value (and its `!important` priority).
+/// If the element had no inline `display`, the property is removed again, so the stylesheets apply like before.
+///
+/// While an element is not displayed, changing its children doesn't cause layout work. This helps when `f` does many DOM changes
+/// and also reads layout properties in between, which would otherwise force a reflow on every read.
+///
+/// This is a pragmatic optimization, not a guarantee: modern browsers already batch most changes, and hiding the element can itself cause a reflow of the rest of the page.
+/// Measure before relying on it. Note that layout properties (like `offsetWidth`) of the hidden element read inside `f` are `0`.
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use wasm_bindgen::JsCast;
+/// use web_sys::HtmlElement;
+/// use webru::{batch_update, create_element, get_element_by_id};
+///
+/// let list: HtmlElement = get_element_by_id("results").unwrap().dyn_into().unwrap();
+///
+/// batch_update(&list, || {
+///     for i in 0..1000 {
+///         let item = create_element("li");
+///         item.set_text_content(Some(&i.to_string()));
+///
+///         list.append_child(&item).unwrap();
+///     }
+/// });
+/// ```
+pub fn batch_update<F>(el: &HtmlElement, f: F)
+where
+    F: FnOnce(),
+{
+    let style = el.style();
+
+    let display = style.get_property_value("display").unwrap();
+    let priority = style.get_property_priority("display");
+
+    style.set_property("display", "none").unwrap();
+
+    f();
+
+    if display.is_empty() {
+        style.remove_property("display").unwrap();
+    } else {
+        style
+            .set_property_with_priority("display", &display, &priority)
+            .unwrap();
+    }
+}
@@ -0,0 +1,151 @@
+use wasm_bindgen::prelude::Closure;
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::window;
+
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+/// Javascript [`requestAnimationFrame()`](https://developer.mozilla.org/en-US/docs/Web/API/window/requestAnimationFrame) method
+///
+/// This function calls the `handler` before the browser repaints the page. Use it for animations instead of [`set_timeout`](crate::set_timeout), as the frames are synchronized with the display and paused in background tabs.
+///
+/// The `handler` receives a [`DOMHighResTimeStamp`](https://developer.mozilla.org/en-US/docs/Web/API/DOMHighResTimeStamp): the time in milliseconds when the frame started. Compare it with the timestamp of the previous frame to compute the delta time.
+///
+/// The `handler` is called only once. To animate continuously, request a new frame inside the `handler`.
+///
+/// It returns the ID of the request which can be used with [`cancel_animation_frame`] to cancel it
+///
+///
+/// # Arguments
+///
+/// * `handler` - The Rust closure to execute before the next repaint
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use weblog::console_log;
+/// use webru::request_animation_frame;
+///
+/// request_animation_frame(|timestamp| {
+///     console_log!("The frame started at ", timestamp);
+/// })
+/// .unwrap();
+/// ```
+pub fn request_animation_frame<T>(handler: T) -> Result<i32, JsValue>
+where
+    T: Fn(f64) + 'static,
+{
+    let window = window().unwrap();
+
+    let callback = Closure::wrap(Box::new(handler) as Box<dyn Fn(f64)>);
+
+    let result = window.request_animation_frame(callback.as_ref().unchecked_ref());
+
+    callback.forget();
+
+    result
+}
+
+/// Javascript [`cancelAnimationFrame()`](https://developer.mozilla.org/en-US/docs/Web/API/Window/cancelAnimationFrame) method
+///
+/// This function cancels a request previously made by the [`request_animation_frame`] function.
+///
+///
+/// # Arguments
+///
+/// * `id` - The ID returned by [`request_animation_frame`]
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use webru::{cancel_animation_frame, request_animation_frame};
+///
+/// let id = request_animation_frame(|_| {}).unwrap();
+///
+/// // the handler will not be called
+/// cancel_animation_frame(id);
+/// ```
+pub fn cancel_animation_frame(id: i32) {
+    window().unwrap().cancel_animation_frame(id).unwrap();
+}
+
+/// Limit how often a closure runs to once per animation frame
+///
+/// This function returns a new closure. Calling it schedules `f` with javascript's [`requestAnimationFrame()`](https://developer.mozilla.org/en-US/docs/Web/API/window/requestAnimationFrame) method,
+/// unless `f` is already scheduled. So no matter how many times it's called, `f` runs at most once per frame, right before the repaint.
+///
+/// This is the recommended way to handle events like `scroll`, `resize` or `mousemove`, which can fire many times per frame.
+///
+///
+/// # Arguments
+///
+/// * `f` - The Rust closure to throttle
+///
+///
+/// # Panics
+///
+/// The returned closure will panic if you call it outside of the web such as `node.js` runtime
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use wasm_bindgen::prelude::Closure;
+/// use wasm_bindgen::JsCast;
+/// use weblog::console_log;
+/// use webru::raf_throttle;
+///
+/// let on_scroll = raf_throttle(|| {
+///     let y = web_sys::window().unwrap().scroll_y().unwrap();
+///     console_log!("Scrolled to ", y);
+/// });
+///
+/// let on_scroll = Closure::wrap(Box::new(on_scroll) as Box<dyn FnMut()>);
+///
+/// web_sys::window()
+///     .unwrap()
+///     .add_event_listener_with_callback("scroll", on_scroll.as_ref().unchecked_ref())
+///     .unwrap();
+///
+/// on_scroll.forget();
+/// ```
+pub fn raf_throttle<T>(f: T) -> impl FnMut()
+where
+    T: FnMut() + 'static,
+{
+    let f = Rc::new(RefCell::new(f));
+    let scheduled = Rc::new(Cell::new(false));
+
+    move || {
+        if scheduled.replace(true) {
+            return;
+        }
+
+        // the closure frees itself after the frame
+        let frame = Closure::once_into_js({
+            let f = Rc::clone(&f);
+            let scheduled = Rc::clone(&scheduled);
+            move || {
+                scheduled.set(false);
+                (f.borrow_mut())();
+            }
+        });
+
+        window()
+            .unwrap()
+            .request_animation_frame(frame.unchecked_ref())
+            .unwrap();
+    }
+}
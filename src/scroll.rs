@@ -0,0 +1,196 @@
+use js_sys::Promise;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{window, HtmlElement, ScrollBehavior, ScrollToOptions};
+
+use crate::{body, document};
+
+use std::cell::RefCell;
+
+/// Smoothly scroll the page to a vertical position over a custom duration
+///
+/// The native [`window.scrollTo`](https://developer.mozilla.org/en-US/docs/Web/API/Window/scrollTo) method with `behavior: "smooth"` doesn't let you control how long the animation takes.
+/// This function animates the scroll position with javascript's [`requestAnimationFrame()`](https://developer.mozilla.org/en-US/docs/Web/API/window/requestAnimationFrame) method using an ease-in-out curve,
+/// and the returned future resolves when the animation is complete.
+///
+///
+/// # Arguments
+///
+/// * `y` - The vertical position (in pixels) to scroll to
+///
+/// * `duration_ms` - How long the animation should take in milliseconds. `0` or a negative value jumps to `y` on the next frame
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use webru::smooth_scroll_to;
+/// use weblog::console_log;
+///
+/// wasm_bindgen_futures::spawn_local(async {
+///     // scroll back to the top in 1.5 seconds
+///     smooth_scroll_to(0.0, 1500.0).await;
+///
+///     console_log!("Reached the top");
+/// });
+/// ```
+pub async fn smooth_scroll_to(y: f64, duration_ms: f64) {
+    let window = window().unwrap();
+
+    let start_y = window.scroll_y().unwrap();
+    let distance = y - start_y;
+
+    let start_time = next_frame().await;
+
+    loop {
+        let now = next_frame().await;
+
+        let progress = if duration_ms <= 0.0 {
+            1.0
+        } else {
+            ((now - start_time) / duration_ms).min(1.0)
+        };
+
+        // `instant` so that a page-wide `scroll-behavior: smooth` doesn't animate every frame again
+        let options = ScrollToOptions::new();
+        options.set_top(start_y + distance * ease_in_out(progress));
+        options.set_behavior(ScrollBehavior::Instant);
+
+        window.scroll_to_with_scroll_to_options(&options);
+
+        if progress >= 1.0 {
+            break;
+        }
+    }
+}
+
+/// Wait for the next animation frame and return its timestamp
+pub(crate) async fn next_frame() -> f64 {
+    let promise = Promise::new(&mut |resolve, _reject| {
+        window().unwrap().request_animation_frame(&resolve).unwrap();
+    });
+
+    JsFuture::from(promise).await.unwrap().as_f64().unwrap()
+}
+
+/// Cubic ease-in-out curve
+fn ease_in_out(t: f64) -> f64 {
+    if t < 0.5 {
+        4.0 * t * t * t
+    } else {
+        1.0 - (-2.0 * t + 2.0).powi(3) / 2.0
+    }
+}
+
+/// Enable or disable smooth scrolling for the whole page
+///
+/// This function sets the CSS [`scroll-behavior`](https://developer.mozilla.org/en-US/docs/Web/CSS/scroll-behavior) property of the `<html>` element to `smooth` or `auto`.
+/// When enabled, anchor links and javascript's `scrollTo`/`scrollBy` methods scroll smoothly instead of jumping.
+///
+/// The property is set as an inline style, so it overrides the stylesheets of the page.
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use webru::set_smooth_scroll;
+///
+/// set_smooth_scroll(true);
+/// ```
+pub fn set_smooth_scroll(enabled: bool) {
+    let root = document()
+        .document_element()
+        .unwrap()
+        .dyn_into::<HtmlElement>()
+        .unwrap();
+
+    root.style()
+        .set_property("scroll-behavior", if enabled { "smooth" } else { "auto" })
+        .unwrap();
+}
+
+thread_local! {
+    /// The number of active [`lock_scroll`] calls and the `overflow` of the `<body>` before the first one
+    static SCROLL_LOCK: RefCell<(u32, String)> = const { RefCell::new((0, String::new())) };
+}
+
+/// Disable the scrolling of the page
+///
+/// This function sets `overflow: hidden` on the `<body>`, which is commonly done while a modal is open.
+/// The previous inline `overflow` value is remembered and restored by [`unlock_scroll`].
+///
+/// Locks can be nested: if two modals call `lock_scroll`, the page is scrollable again only after both of them called [`unlock_scroll`].
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use webru::{lock_scroll, unlock_scroll};
+///
+/// // when the modal opens
+/// lock_scroll();
+///
+/// // when the modal closes
+/// unlock_scroll();
+/// ```
+pub fn lock_scroll() {
+    SCROLL_LOCK.with(|lock| {
+        let mut lock = lock.borrow_mut();
+        let style = body().style();
+
+        if lock.0 == 0 {
+            lock.1 = style.get_property_value("overflow").unwrap();
+            style.set_property("overflow", "hidden").unwrap();
+        }
+
+        lock.0 += 1;
+    });
+}
+
+/// Enable the scrolling of the page again
+///
+/// This function undoes one [`lock_scroll`] call. When the last lock is released, the `<body>`'s previous inline `overflow` value is restored.
+///
+/// Calling it without an active lock does nothing.
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+pub fn unlock_scroll() {
+    SCROLL_LOCK.with(|lock| {
+        let mut lock = lock.borrow_mut();
+
+        if lock.0 == 0 {
+            return;
+        }
+
+        lock.0 -= 1;
+
+        if lock.0 == 0 {
+            let style = body().style();
+
+            if lock.1.is_empty() {
+                style.remove_property("overflow").unwrap();
+            } else {
+                style.set_property("overflow", &lock.1).unwrap();
+            }
+        }
+    });
+}
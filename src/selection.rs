@@ -0,0 +1,118 @@
+use wasm_bindgen::JsValue;
+use web_sys::{window, Element, Range};
+
+/// Get the [`Range`](https://developer.mozilla.org/en-US/docs/Web/API/Range) of the current selection
+///
+/// This function is equivalent to javascript's [`window.getSelection().getRangeAt(0)`](https://developer.mozilla.org/en-US/docs/Web/API/Selection/getRangeAt) method.
+///
+/// It returns `None` if nothing is selected. Note that a collapsed selection (a blinking caret) is still a range, with the same start and end.
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use weblog::console_log;
+/// use webru::selection_range;
+///
+/// if let Some(range) = selection_range() {
+///     console_log!("Selection starts at offset ", range.start_offset().unwrap());
+/// }
+/// ```
+pub fn selection_range() -> Option<Range> {
+    let selection = window().unwrap().get_selection().unwrap()?;
+
+    if selection.range_count() == 0 {
+        return None;
+    }
+
+    selection.get_range_at(0).ok()
+}
+
+/// Wrap the current selection in an element
+///
+/// This function is equivalent to javascript's [`range.surroundContents(el)`](https://developer.mozilla.org/en-US/docs/Web/API/Range/surroundContents) method, called on the range of [`selection_range`].
+/// It's the basic building block of rich text editors, for example to make the selected text bold by wrapping it in a `<b>` element.
+///
+/// If nothing is selected, this function does nothing.
+///
+/// It returns the `Err` thrown by `surroundContents()` if the selection only partially contains an element, for example when it starts inside one paragraph and ends inside another.
+///
+///
+/// # Arguments
+///
+/// * `el` - The element to wrap the selection in. It should be empty, as its children are replaced by the selected content
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use webru::{create_element, surround_selection};
+///
+/// let bold = create_element("b");
+///
+/// surround_selection(&bold).unwrap();
+/// ```
+pub fn surround_selection(el: &Element) -> Result<(), JsValue> {
+    match selection_range() {
+        Some(range) => range.surround_contents(el),
+        None => Ok(()),
+    }
+}
+
+/// Get the text selected by the user
+///
+/// This function is equivalent to javascript's [`window.getSelection().toString()`](https://developer.mozilla.org/en-US/docs/Web/API/Selection/toString) method.
+///
+/// It returns an empty string if nothing is selected. Text selected inside a shadow DOM is not included.
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use webru::{clear_selection, selected_text};
+///
+/// let quote = selected_text();
+///
+/// if !quote.is_empty() {
+///     // share the quote
+///     clear_selection();
+/// }
+/// ```
+pub fn selected_text() -> String {
+    match window().unwrap().get_selection().unwrap() {
+        Some(selection) => selection.to_string().into(),
+        None => String::new(),
+    }
+}
+
+/// Deselect everything
+///
+/// This function is equivalent to javascript's [`window.getSelection().removeAllRanges()`](https://developer.mozilla.org/en-US/docs/Web/API/Selection/removeAllRanges) method.
+///
+/// If nothing is selected, this function does nothing.
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+pub fn clear_selection() {
+    if let Some(selection) = window().unwrap().get_selection().unwrap() {
+        selection.remove_all_ranges().unwrap();
+    }
+}
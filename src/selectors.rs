@@ -1,4 +1,5 @@
-use web_sys::{Element, HtmlCollection, Node, NodeList};
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{Element, HtmlCollection, HtmlMetaElement, Node, NodeList};
 
 use crate::document;
 
@@ -19,7 +20,7 @@ use crate::document;
 ///
 /// # Example
 ///
-/// ```
+/// ```no_run
 /// use webru::{body, create_element, get_element_by_id};
 ///
 /// // `id` property for the new <p> element
@@ -52,6 +53,35 @@ pub fn get_element_by_id(id: &str) -> Option<Element> {
     document().get_element_by_id(id)
 }
 
+/// Javascript [`document.getElementById`](https://developer.mozilla.org/en-US/docs/Web/API/Document/getElementById) method
+///
+/// This function does the same thing as the [`get_element_by_id`] function does.
+///
+/// But instead of returning `None` when no element matches, it returns an `Err` with a message like `no element with id 'main'`. So you can propagate it with the `?` operator.
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use wasm_bindgen::JsValue;
+/// use webru::require_element_by_id;
+///
+/// fn render() -> Result<(), JsValue> {
+///     let app = require_element_by_id("app")?;
+///     app.set_inner_html("Hello world");
+///
+///     Ok(())
+/// }
+/// ```
+pub fn require_element_by_id(id: &str) -> Result<Element, JsValue> {
+    get_element_by_id(id).ok_or_else(|| JsValue::from_str(&format!("no element with id '{}'", id)))
+}
+
 /// Javascript [`document.getElementsByClassName`](https://developer.mozilla.org/en-US/docs/Web/API/Document/getElementsByClassName) method
 ///
 /// It returns An [`HTMLCollection`](https://docs.rs/web-sys/0.3.56/web_sys/struct.HtmlCollection.html) object. A collection of elements with the specified class name.
@@ -71,7 +101,7 @@ pub fn get_element_by_id(id: &str) -> Option<Element> {
 ///
 /// # Example
 ///
-/// ```
+/// ```no_run
 /// use webru::{body, create_element, get_elements_by_classname};
 ///
 /// // `class` property for the new <p> element
@@ -120,15 +150,91 @@ pub fn get_elements_by_classname(classname: &str) -> HtmlCollection {
 /// [`Vec<Element>`]: <https://docs.rs/web-sys/0.3.56/web_sys/struct.Element.html>
 ///
 pub fn get_elements_by_classname_inside_vec(classname: &str) -> Vec<Element> {
-    let mut i = 0;
-    let mut vec: Vec<Element> = Vec::new();
+    html_collection_iter(get_elements_by_classname(classname)).collect()
+}
 
-    while let Some(element) = get_elements_by_classname(classname).item(i) {
-        vec.push(element);
-        i += 1;
-    }
+/// Iterate over the elements of an [`HtmlCollection`](https://docs.rs/web-sys/0.3.56/web_sys/struct.HtmlCollection.html)
+///
+/// The iterator yields `collection.item(0)`, `collection.item(1)` and so on, until an item is `None`. The elements are read lazily, so nothing is allocated.
+///
+/// Note that an `HtmlCollection` is live: if elements are added to or removed from the document while iterating, the iterator sees the changes.
+/// Collect it into a `Vec` first (or use functions like [`get_elements_by_classname_inside_vec`]) if you modify the document inside the loop.
+///
+///
+/// # Arguments
+///
+/// * `collection` - The collection to iterate
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use webru::{get_elements_by_classname, html_collection_iter};
+///
+/// for element in html_collection_iter(get_elements_by_classname("card")) {
+///     element.set_attribute("tabindex", "0").unwrap();
+/// }
+/// ```
+pub fn html_collection_iter(collection: HtmlCollection) -> impl Iterator<Item = Element> {
+    (0..).map_while(move |i| collection.item(i))
+}
+
+/// Get the child elements of an element
+///
+/// This function collects javascript's [`element.children`](https://developer.mozilla.org/en-US/docs/Web/API/Element/children) property into a `Vec`.
+///
+/// Unlike [`child_nodes()`](https://developer.mozilla.org/en-US/docs/Web/API/Node/childNodes), only elements are included, so the text nodes of the whitespace between tags don't get in the way.
+/// Only the direct children are included, not their descendants.
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use webru::{children, get_element_by_id};
+///
+/// let menu = get_element_by_id("menu").unwrap();
+///
+/// for item in children(&menu) {
+///     item.set_attribute("role", "menuitem").unwrap();
+/// }
+/// ```
+pub fn children(el: &Element) -> Vec<Element> {
+    html_collection_iter(el.children()).collect()
+}
+
+/// Get the first child element of an element
+///
+/// This function is equivalent to javascript's [`element.firstElementChild`](https://developer.mozilla.org/en-US/docs/Web/API/Element/firstElementChild) property. Text and comment nodes are skipped.
+///
+/// It returns `None` if the element has no child elements.
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+pub fn first_child_element(el: &Element) -> Option<Element> {
+    el.first_element_child()
+}
 
-    vec
+/// Get the last child element of an element
+///
+/// This function is equivalent to javascript's [`element.lastElementChild`](https://developer.mozilla.org/en-US/docs/Web/API/Element/lastElementChild) property. Text and comment nodes are skipped.
+///
+/// It returns `None` if the element has no child elements.
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+pub fn last_child_element(el: &Element) -> Option<Element> {
+    el.last_element_child()
 }
 
 /// Javascript [`document.querySelector`](https://developer.mozilla.org/en-US/docs/Web/API/Document/querySelector) method
@@ -150,7 +256,7 @@ pub fn get_elements_by_classname_inside_vec(classname: &str) -> Vec<Element> {
 ///
 /// # Example
 ///
-/// ```
+/// ```no_run
 /// use webru::{body, create_element, query_selector};
 ///
 /// // id of the <p>
@@ -223,13 +329,103 @@ pub fn query_selector_all(selector: &str) -> NodeList {
 /// * This function will panic if the `selector` is not a valid CSS selector
 ///
 pub fn query_selector_all_inside_vec(selector: &str) -> Vec<Node> {
-    let mut i = 0;
-    let mut vec: Vec<Node> = Vec::new();
+    node_list_iter(query_selector_all(selector)).collect()
+}
 
-    while let Some(element) = query_selector_all(selector).item(i) {
-        vec.push(element);
-        i += 1;
-    }
+/// Iterate over the nodes of a [`NodeList`](https://docs.rs/web-sys/0.3.56/web_sys/struct.NodeList.html)
+///
+/// The iterator yields `list.item(0)`, `list.item(1)` and so on, until an item is `None`. The nodes are read lazily, so nothing is allocated.
+///
+/// The `NodeList` returned by [`query_selector_all`] is static, so it doesn't change when the document is modified while iterating.
+///
+///
+/// # Arguments
+///
+/// * `list` - The list to iterate
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use wasm_bindgen::JsCast;
+/// use web_sys::Element;
+/// use webru::{node_list_iter, query_selector_all};
+///
+/// for node in node_list_iter(query_selector_all("img[data-src]")) {
+///     let image = node.dyn_into::<Element>().unwrap();
+///     let src = image.get_attribute("data-src").unwrap();
+///
+///     image.set_attribute("src", &src).unwrap();
+/// }
+/// ```
+pub fn node_list_iter(list: NodeList) -> impl Iterator<Item = Node> {
+    (0..).map_while(move |i| list.item(i))
+}
 
-    vec
+/// Get all the elements matching a selector and a predicate
+///
+/// This function runs [`query_selector_all_inside_vec`] and keeps the elements for which `predicate` returns `true`.
+/// It's useful for conditions which can't be expressed with a CSS selector, like "all the buttons whose text contains X".
+///
+/// Nodes which are not elements are skipped. The elements are returned in document order.
+///
+///
+/// # Panics
+///
+/// * This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+/// * This function will panic if the `selector` is not a valid CSS selector
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use webru::query_filter;
+///
+/// let delete_buttons = query_filter("button", |button| {
+///     button
+///         .text_content()
+///         .unwrap_or_default()
+///         .contains("Delete")
+/// });
+/// ```
+pub fn query_filter<F>(selector: &str, predicate: F) -> Vec<Element>
+where
+    F: Fn(&Element) -> bool,
+{
+    query_selector_all_inside_vec(selector)
+        .into_iter()
+        .filter_map(|node| node.dyn_into::<Element>().ok())
+        .filter(|el| predicate(el))
+        .collect()
+}
+
+/// Get the content of a `<meta>` tag
+///
+/// This function finds the first `<meta name="...">` tag with the given `name` and returns its [`content`](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/meta#attr-content) attribute.
+/// Apps often read configuration like CSRF tokens or the theme color from these tags.
+///
+/// It returns `None` if there is no such tag.
+///
+/// The `name` is compared as a plain string, not put into a selector, so any characters are allowed.
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use webru::meta_content;
+///
+/// // <meta name="csrf-token" content="...">
+/// let token = meta_content("csrf-token").expect("the CSRF token is missing");
+/// ```
+pub fn meta_content(name: &str) -> Option<String> {
+    node_list_iter(query_selector_all("meta[name]"))
+        .filter_map(|node| node.dyn_into::<HtmlMetaElement>().ok())
+        .find(|meta| meta.name() == name)
+        .map(|meta| meta.content())
 }
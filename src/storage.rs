@@ -0,0 +1,262 @@
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{window, Storage, StorageEvent};
+
+use crate::events::{listen, EventListenerHandle};
+
+/// A change of the `localStorage`, made by another tab or window
+///
+/// It's passed to the handler of [`on_storage_change`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StorageChange {
+    /// The key which changed. It's `None` if the whole storage was cleared
+    pub key: Option<String>,
+    /// The value before the change. It's `None` if the key was just added
+    pub old_value: Option<String>,
+    /// The value after the change. It's `None` if the key was removed
+    pub new_value: Option<String>,
+}
+
+/// Listen to the changes of the storage made by other tabs
+///
+/// The `handler` is called on the window's [`storage`](https://developer.mozilla.org/en-US/docs/Web/API/Window/storage_event) event,
+/// which fires when the `localStorage` is modified by *another* document of the same origin. Changes made by the current page don't fire it.
+///
+/// This is useful for keeping the state of several tabs in sync, for example logging out everywhere.
+///
+/// The listener is removed when the returned [`EventListenerHandle`] is dropped.
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use webru::{on_storage_change, reload, StorageChange};
+///
+/// let listener = on_storage_change(|change: StorageChange| {
+///     // the user logged out in another tab
+///     if change.key.as_deref() == Some("token") && change.new_value.is_none() {
+///         reload();
+///     }
+/// });
+///
+/// listener.forget();
+/// ```
+pub fn on_storage_change<T>(mut handler: T) -> EventListenerHandle
+where
+    T: FnMut(StorageChange) + 'static,
+{
+    listen(&window().unwrap(), "storage", move |event| {
+        if let Some(event) = event.dyn_ref::<StorageEvent>() {
+            handler(StorageChange {
+                key: event.key(),
+                old_value: event.old_value(),
+                new_value: event.new_value(),
+            });
+        }
+    })
+}
+
+/// Store a value in the `localStorage`
+///
+/// This function is equivalent to javascript's [`localStorage.setItem()`](https://developer.mozilla.org/en-US/docs/Web/API/Storage/setItem) method.
+/// The value is kept after the page is closed, until it's removed.
+///
+/// It returns `Err` if the `localStorage` is not available (for example when it's disabled in the browser's settings) or if it's full.
+///
+///
+/// # Arguments
+///
+/// * `key` - The key of the value
+///
+/// * `value` - The value to store
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use webru::{local_storage_get, local_storage_set};
+///
+/// local_storage_set("theme", "dark").unwrap();
+///
+/// assert_eq!(local_storage_get("theme"), Some("dark".to_string()));
+/// ```
+pub fn local_storage_set(key: &str, value: &str) -> Result<(), JsValue> {
+    local_storage()
+        .ok_or_else(|| JsValue::from_str("localStorage is not available"))?
+        .set_item(key, value)
+}
+
+/// Get a value from the `localStorage`
+///
+/// This function is equivalent to javascript's [`localStorage.getItem()`](https://developer.mozilla.org/en-US/docs/Web/API/Storage/getItem) method.
+///
+/// It returns `None` if there is no value for the `key` or if the `localStorage` is not available.
+///
+///
+/// # Arguments
+///
+/// * `key` - The key of the value
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+pub fn local_storage_get(key: &str) -> Option<String> {
+    local_storage()?.get_item(key).ok().flatten()
+}
+
+/// Remove a value from the `localStorage`
+///
+/// This function is equivalent to javascript's [`localStorage.removeItem()`](https://developer.mozilla.org/en-US/docs/Web/API/Storage/removeItem) method.
+///
+/// It does nothing if there is no value for the `key` or if the `localStorage` is not available.
+///
+///
+/// # Arguments
+///
+/// * `key` - The key of the value
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+pub fn local_storage_remove(key: &str) {
+    if let Some(storage) = local_storage() {
+        let _ = storage.remove_item(key);
+    }
+}
+
+/// Remove all the values from the `localStorage`
+///
+/// This function is equivalent to javascript's [`localStorage.clear()`](https://developer.mozilla.org/en-US/docs/Web/API/Storage/clear) method.
+/// Note that it removes the values stored by every page of the same origin, not only the ones stored by your code.
+///
+/// It does nothing if the `localStorage` is not available.
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+pub fn local_storage_clear() {
+    if let Some(storage) = local_storage() {
+        let _ = storage.clear();
+    }
+}
+
+/// Store a value in the `sessionStorage`
+///
+/// This function does the same thing as the [`local_storage_set`] function does, but with javascript's [`sessionStorage`](https://developer.mozilla.org/en-US/docs/Web/API/Window/sessionStorage).
+/// Unlike the `localStorage`, the `sessionStorage` is cleared when the tab is closed, and every tab has its own.
+///
+/// It returns `Err` if the `sessionStorage` is not available or if it's full.
+///
+///
+/// # Arguments
+///
+/// * `key` - The key of the value
+///
+/// * `value` - The value to store
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use webru::{session_storage_get, session_storage_set};
+///
+/// // remember the step of a wizard until the tab is closed
+/// session_storage_set("step", "2").unwrap();
+///
+/// assert_eq!(session_storage_get("step"), Some("2".to_string()));
+/// ```
+pub fn session_storage_set(key: &str, value: &str) -> Result<(), JsValue> {
+    session_storage()
+        .ok_or_else(|| JsValue::from_str("sessionStorage is not available"))?
+        .set_item(key, value)
+}
+
+/// Get a value from the `sessionStorage`
+///
+/// This function does the same thing as the [`local_storage_get`] function does, but with javascript's [`sessionStorage`](https://developer.mozilla.org/en-US/docs/Web/API/Window/sessionStorage).
+///
+/// It returns `None` if there is no value for the `key` or if the `sessionStorage` is not available.
+///
+///
+/// # Arguments
+///
+/// * `key` - The key of the value
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+pub fn session_storage_get(key: &str) -> Option<String> {
+    session_storage()?.get_item(key).ok().flatten()
+}
+
+/// Remove a value from the `sessionStorage`
+///
+/// This function does the same thing as the [`local_storage_remove`] function does, but with javascript's [`sessionStorage`](https://developer.mozilla.org/en-US/docs/Web/API/Window/sessionStorage).
+///
+/// It does nothing if there is no value for the `key` or if the `sessionStorage` is not available.
+///
+///
+/// # Arguments
+///
+/// * `key` - The key of the value
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+pub fn session_storage_remove(key: &str) {
+    if let Some(storage) = session_storage() {
+        let _ = storage.remove_item(key);
+    }
+}
+
+/// Remove all the values from the `sessionStorage`
+///
+/// This function does the same thing as the [`local_storage_clear`] function does, but with javascript's [`sessionStorage`](https://developer.mozilla.org/en-US/docs/Web/API/Window/sessionStorage).
+/// Only the storage of the current tab is cleared.
+///
+/// It does nothing if the `sessionStorage` is not available.
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+pub fn session_storage_clear() {
+    if let Some(storage) = session_storage() {
+        let _ = storage.clear();
+    }
+}
+
+/// The `localStorage`. Accessing it throws if it's disabled
+fn local_storage() -> Option<Storage> {
+    window().unwrap().local_storage().ok().flatten()
+}
+
+/// The `sessionStorage`. Accessing it throws if it's disabled
+fn session_storage() -> Option<Storage> {
+    window().unwrap().session_storage().ok().flatten()
+}
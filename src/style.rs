@@ -0,0 +1,268 @@
+use wasm_bindgen::JsCast;
+use web_sys::{window, CssStyleDeclaration, Element, HtmlElement};
+
+use crate::document;
+
+use std::collections::HashMap;
+
+/// Get the computed color of an element as an `(r, g, b, a)` tuple
+///
+/// This function reads the value from javascript's [`getComputedStyle()`](https://developer.mozilla.org/en-US/docs/Web/API/Window/getComputedStyle) method.
+/// Browsers normalize computed colors into the `rgb()` or `rgba()` format, so named colors like `red` or hex colors like `#ff0000` will work too.
+///
+/// It returns `None` if the computed value can't be parsed as a color
+///
+///
+/// # Arguments
+///
+/// * `el` - The element to read the color from
+///
+/// * `property` - A CSS property holding a color, such as `color`, `background-color` or `border-top-color`
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use wasm_bindgen::JsCast;
+/// use web_sys::HtmlElement;
+/// use webru::{body, computed_color, create_element};
+///
+/// let div = create_element("div");
+///
+/// div.dyn_ref::<HtmlElement>()
+///     .unwrap()
+///     .style()
+///     .set_property("color", "red")
+///     .unwrap();
+///
+/// body().append_child(&div).unwrap();
+///
+/// assert_eq!(computed_color(&div, "color"), Some((255, 0, 0, 1.0)));
+/// ```
+pub fn computed_color(el: &Element, property: &str) -> Option<(u8, u8, u8, f64)> {
+    parse_color(&computed_property(el, property)?)
+}
+
+/// Read several computed CSS properties of an element at once
+///
+/// This function calls javascript's [`getComputedStyle()`](https://developer.mozilla.org/en-US/docs/Web/API/Window/getComputedStyle) method once and reads every requested property from it.
+/// It's handy for debugging or for taking a snapshot of an element's style.
+///
+/// The keys of the returned map are the requested property names. Unknown properties map to an empty string.
+///
+///
+/// # Arguments
+///
+/// * `el` - The element to read the style from
+///
+/// * `properties` - The CSS property names, such as `color` or `margin-top`
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use std::collections::HashMap;
+/// use webru::{body, computed_styles};
+///
+/// let styles: HashMap<String, String> = computed_styles(&body(), &["display", "margin-top"]);
+///
+/// assert_eq!(styles["display"], "block");
+/// ```
+pub fn computed_styles(el: &Element, properties: &[&str]) -> HashMap<String, String> {
+    let style = match window().unwrap().get_computed_style(el).unwrap() {
+        Some(style) => style,
+        None => return HashMap::new(),
+    };
+
+    properties
+        .iter()
+        .map(|property| {
+            (
+                property.to_string(),
+                style.get_property_value(property).unwrap(),
+            )
+        })
+        .collect()
+}
+
+/// Zoom the whole page
+///
+/// This function sets the CSS [`zoom`](https://developer.mozilla.org/en-US/docs/Web/CSS/zoom) property of the `<html>` element, which scales the content and the layout of the page, like the zoom of the browser does.
+/// It's useful for accessibility controls such as "larger text" buttons.
+///
+/// Note that `zoom` was a non-standard property for a long time. It's now part of the CSS specification, but older browsers (for example Firefox before version 126) ignore it.
+/// It's used instead of `transform: scale()` because a transform doesn't change the layout, so the scaled page would overflow the window instead of reflowing.
+///
+///
+/// # Arguments
+///
+/// * `factor` - The zoom factor. `1.0` is the normal size, `1.5` is 150%
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use webru::{get_zoom, set_zoom};
+///
+/// // "zoom in" button
+/// set_zoom(get_zoom() + 0.1);
+/// ```
+pub fn set_zoom(factor: f64) {
+    root_style()
+        .set_property("zoom", &factor.to_string())
+        .unwrap();
+}
+
+/// Get the zoom factor of the page set by [`set_zoom`]
+///
+/// This function reads the inline CSS [`zoom`](https://developer.mozilla.org/en-US/docs/Web/CSS/zoom) property of the `<html>` element. Percentages like `150%` are converted to factors like `1.5`.
+///
+/// It returns `1.0` if no zoom is set. Note that the zoom of the browser itself is not included.
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+pub fn get_zoom() -> f64 {
+    let value = root_style().get_property_value("zoom").unwrap();
+    let value = value.trim();
+
+    let zoom = match value.strip_suffix('%') {
+        Some(percent) => percent.parse::<f64>().ok().map(|percent| percent / 100.0),
+        None => value.parse().ok(),
+    };
+
+    zoom.unwrap_or(1.0)
+}
+
+/// Set several inline styles of an element at once
+///
+/// This function calls javascript's [`style.setProperty()`](https://developer.mozilla.org/en-US/docs/Web/API/CSSStyleDeclaration/setProperty) method for every `(property, value)` pair, in order.
+/// Properties are written in the CSS form like `background-color`, not the camel case form.
+///
+/// Invalid property names and invalid values are silently ignored by the browser, like in a stylesheet.
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use wasm_bindgen::JsCast;
+/// use web_sys::HtmlElement;
+/// use webru::{get_element_by_id, set_styles};
+///
+/// let tooltip: HtmlElement = get_element_by_id("tooltip").unwrap().dyn_into().unwrap();
+///
+/// set_styles(
+///     &tooltip,
+///     &[("display", "block"), ("left", "120px"), ("top", "40px")],
+/// );
+/// ```
+pub fn set_styles(el: &HtmlElement, styles: &[(&str, &str)]) {
+    let style = el.style();
+
+    for (property, value) in styles {
+        style.set_property(property, value).unwrap();
+    }
+}
+
+/// Add a `<style>` element with some CSS to the page
+///
+/// This function creates a [`<style>`](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/style) element, sets its text to `css` and appends it to the `<head>`, so the rules apply to the whole document.
+/// It's useful for themes or component styles which are only known at runtime.
+///
+/// The element is returned, so you can remove the styles again with `.remove()` (or change them with `set_text_content`).
+///
+/// The CSS is not validated. Invalid rules are ignored by the browser, like in a stylesheet.
+///
+///
+/// # Panics
+///
+/// * This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+/// * This function will panic if the document has no `<head>`
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use webru::inject_style;
+///
+/// let dark_theme = inject_style("body { background: #111; color: #eee; }");
+///
+/// // switch back to the light theme
+/// dark_theme.remove();
+/// ```
+pub fn inject_style(css: &str) -> Element {
+    let style = document().create_element("style").unwrap();
+    style.set_text_content(Some(css));
+
+    document().head().unwrap().append_child(&style).unwrap();
+
+    style
+}
+
+/// The inline style of the `<html>` element
+fn root_style() -> CssStyleDeclaration {
+    document()
+        .document_element()
+        .unwrap()
+        .dyn_into::<HtmlElement>()
+        .unwrap()
+        .style()
+}
+
+/// Read a single property from javascript's `getComputedStyle()`
+pub(crate) fn computed_property(el: &Element, property: &str) -> Option<String> {
+    window()
+        .unwrap()
+        .get_computed_style(el)
+        .unwrap()
+        .map(|style| style.get_property_value(property).unwrap())
+}
+
+/// Parse `rgb(r, g, b)`, `rgba(r, g, b, a)` and the space separated `rgb(r g b / a)` syntax
+fn parse_color(value: &str) -> Option<(u8, u8, u8, f64)> {
+    let value = value.trim();
+
+    let inner = value
+        .strip_prefix("rgba(")
+        .or_else(|| value.strip_prefix("rgb("))?
+        .strip_suffix(')')?;
+
+    let parts: Vec<&str> = inner
+        .split(|c: char| c == ',' || c == '/' || c.is_whitespace())
+        .filter(|part| !part.is_empty())
+        .collect();
+
+    let channel = |part: &str| -> Option<u8> {
+        let number: f64 = part.parse().ok()?;
+        Some(number.round().clamp(0.0, 255.0) as u8)
+    };
+
+    match parts.as_slice() {
+        [r, g, b] => Some((channel(r)?, channel(g)?, channel(b)?, 1.0)),
+        [r, g, b, a] => Some((channel(r)?, channel(g)?, channel(b)?, a.parse().ok()?)),
+        _ => None,
+    }
+}
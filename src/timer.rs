@@ -1,11 +1,74 @@
 #![allow(dead_code, unused)]
 
+use js_sys::{Function, Math};
 use wasm_bindgen::prelude::Closure;
 use wasm_bindgen::{JsCast, JsValue};
-use web_sys::window;
+use web_sys::{console, window, AbortSignal};
 
+use std::cell::{Cell, RefCell};
 use std::rc::Rc;
 
+use crate::events::{listen, EventListenerHandle};
+
+thread_local! {
+    /// Whether the timer functions log their calls to the console
+    static TIMER_DEBUG: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Enable or disable logging of the timer functions
+///
+/// When enabled, every call to [`set_timeout`], [`clear_timeout`], [`set_interval`] and [`clear_interval`] logs the timer's id to the console.
+/// The other timers of the crate (like [`Timeout`], [`Interval`], [`Countdown`], [`IdleTimer`] or the timeout of [`wait_for_transition`](crate::wait_for_transition)) are logged too.
+///
+/// This is useful to track down timers that are never cleared. It's disabled by default.
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use webru::{clear_interval, set_interval, set_timer_debug};
+///
+/// set_timer_debug(true);
+///
+/// // logs "[webru] set_interval: 1"
+/// let interval_id = set_interval(|| {}, 1000).unwrap();
+///
+/// // logs "[webru] clear_interval: 1"
+/// clear_interval(interval_id);
+/// ```
+pub fn set_timer_debug(enabled: bool) {
+    TIMER_DEBUG.with(|debug| debug.set(enabled));
+}
+
+/// Log a timer call if [`set_timer_debug`] is enabled
+fn debug_timer(function: &str, id: i32) {
+    if TIMER_DEBUG.with(Cell::get) {
+        console::log_1(&format!("[webru] {}: {}", function, id).into());
+    }
+}
+
+/// Call javascript's `setTimeout()` with a function and log it
+pub(crate) fn start_timeout(callback: &Function, timeout: i32) -> Result<i32, JsValue> {
+    let timeout_id = window()
+        .unwrap()
+        .set_timeout_with_callback_and_timeout_and_arguments_0(callback, timeout)?;
+
+    debug_timer("set_timeout", timeout_id);
+
+    Ok(timeout_id)
+}
+
+/// Call javascript's `setInterval()` with a function and log it
+fn start_interval(callback: &Function, timeout: i32) -> Result<i32, JsValue> {
+    let interval_id = window()
+        .unwrap()
+        .set_interval_with_callback_and_timeout_and_arguments_0(callback, timeout)?;
+
+    debug_timer("set_interval", interval_id);
+
+    Ok(interval_id)
+}
+
 /// Javascript [`setTimeout()`](https://developer.mozilla.org/en-US/docs/Web/API/setTimeout) function
 ///
 /// This function sets a timer which executes a function or specified piece of code once the timer expires.
@@ -27,7 +90,7 @@ use std::rc::Rc;
 ///
 /// # Example
 ///
-/// ```
+/// ```no_run
 /// use webru::{set_timeout};
 /// use weblog::console_log;
 ///
@@ -56,24 +119,140 @@ use std::rc::Rc;
 /// .unwrap();
 /// ```
 ///
-pub fn set_timeout<T: 'static>(handler: T, timeout: i32) -> Result<i32, JsValue>
+pub fn set_timeout<T>(handler: T, timeout: i32) -> Result<i32, JsValue>
 where
-    T: Fn(),
+    T: Fn() + 'static,
 {
-    let window = window().unwrap();
-
     let callback = Closure::wrap(Box::new(handler) as Box<dyn Fn()>);
 
-    let result = window.set_timeout_with_callback_and_timeout_and_arguments_0(
-        callback.as_ref().unchecked_ref(),
-        timeout,
-    );
+    let result = start_timeout(callback.as_ref().unchecked_ref(), timeout);
 
     callback.forget();
 
     result
 }
 
+/// Javascript [`setTimeout()`](https://developer.mozilla.org/en-US/docs/Web/API/setTimeout) function with an argument for the handler
+///
+/// This function does the same thing as the [`set_timeout`] function does, but the `handler` receives `arg` when the timer expires.
+/// So you can pass data to the handler without capturing it with `move` or wrapping it in `Rc<Cell<_>>`.
+///
+/// `arg` is cloned when the handler is called.
+///
+/// It returns the ID of this timer which can be used with [`clear_timeout`] to cancel the timer
+///
+///
+/// # Arguments
+///
+/// * `handler` - The Rust closure to execute
+///
+/// * `timeout` - Number of milliseconds to wait before executing the code in `handler`.
+///
+/// * `arg` - The value passed to `handler`
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use weblog::console_log;
+/// use webru::set_timeout_with_args;
+///
+/// let message = String::from("Your session expires in 1 minute");
+///
+/// set_timeout_with_args(
+///     // This closure will execute after 4 seconds
+///     |message: String| {
+///         console_log!(message);
+///     },
+///     4000,
+///     message,
+/// )
+/// .unwrap();
+/// ```
+pub fn set_timeout_with_args<A, T>(handler: T, timeout: i32, arg: A) -> Result<i32, JsValue>
+where
+    T: Fn(A) + 'static,
+    A: Clone + 'static,
+{
+    set_timeout(move || handler(arg.clone()), timeout)
+}
+
+/// Javascript [`setTimeout()`](https://developer.mozilla.org/en-US/docs/Web/API/setTimeout) function which can be canceled with an [`AbortSignal`](https://developer.mozilla.org/en-US/docs/Web/API/AbortSignal)
+///
+/// This function does the same thing as the [`set_timeout`] function does, but the timer is cleared when the `signal` is aborted.
+/// So a single [`AbortController`](https://developer.mozilla.org/en-US/docs/Web/API/AbortController) can cancel several timers, along with other abortable operations like `fetch`.
+///
+/// If the `signal` is already aborted, the `handler` is never called.
+///
+/// It returns the ID of this timer which can also be used with [`clear_timeout`]
+///
+///
+/// # Arguments
+///
+/// * `handler` - The Rust closure to execute
+///
+/// * `timeout` - Number of milliseconds to wait before executing the code in `handler`.
+///
+/// * `signal` - The signal which cancels the timer
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use web_sys::AbortController;
+/// use weblog::console_log;
+/// use webru::set_timeout_with_signal;
+///
+/// let controller = AbortController::new().unwrap();
+///
+/// set_timeout_with_signal(|| console_log!("first"), 1000, &controller.signal()).unwrap();
+/// set_timeout_with_signal(|| console_log!("second"), 2000, &controller.signal()).unwrap();
+///
+/// // cancel both timers
+/// controller.abort();
+/// ```
+pub fn set_timeout_with_signal<T>(
+    handler: T,
+    timeout: i32,
+    signal: &AbortSignal,
+) -> Result<i32, JsValue>
+where
+    T: Fn() + 'static,
+{
+    let abort_listener: Rc<RefCell<Option<EventListenerHandle>>> = Rc::new(RefCell::new(None));
+
+    let timeout_id = set_timeout(
+        {
+            let abort_listener = Rc::clone(&abort_listener);
+            move || {
+                // the timer can't be canceled anymore
+                abort_listener.borrow_mut().take();
+                handler();
+            }
+        },
+        timeout,
+    )?;
+
+    if signal.aborted() {
+        clear_timeout(timeout_id);
+    } else {
+        *abort_listener.borrow_mut() =
+            Some(listen(signal, "abort", move |_| clear_timeout(timeout_id)));
+    }
+
+    Ok(timeout_id)
+}
+
 /// Javascript [`clearTimeout()`](https://developer.mozilla.org/en-US/docs/Web/API/clearTimeout) function
 ///
 /// This function cancels a timeout previously established by calling javascript's [`setTimeout()`] fucntion.
@@ -90,7 +269,7 @@ where
 ///
 /// # Example
 ///
-/// ```
+/// ```no_run
 /// use weblog::console_log;
 /// use webru::{clear_timeout, set_timeout};
 ///
@@ -112,6 +291,8 @@ pub fn clear_timeout(timeout_id: i32) {
     let window = window().unwrap();
 
     window.clear_timeout_with_handle(timeout_id);
+
+    debug_timer("clear_timeout", timeout_id);
 }
 
 /// Javascript [`setInterval()`](https://developer.mozilla.org/en-US/docs/Web/API/setInterval) method
@@ -135,7 +316,7 @@ pub fn clear_timeout(timeout_id: i32) {
 ///
 /// # Example
 ///
-/// ```
+/// ```no_run
 /// use std::cell::Cell;    
 /// use weblog::console_log;
 /// use webru::set_interval;
@@ -155,18 +336,13 @@ pub fn clear_timeout(timeout_id: i32) {
 ///
 /// [`clearInterval()`]: <https://developer.mozilla.org/en-US/docs/Web/API/clearInterval>
 ///
-pub fn set_interval<T: 'static>(handler: T, timeout: i32) -> Result<i32, JsValue>
+pub fn set_interval<T>(handler: T, timeout: i32) -> Result<i32, JsValue>
 where
-    T: Fn(),
+    T: Fn() + 'static,
 {
-    let window = window().unwrap();
-
     let callback = Closure::wrap(Box::new(handler) as Box<dyn Fn()>);
 
-    let result = window.set_interval_with_callback_and_timeout_and_arguments_0(
-        callback.as_ref().unchecked_ref(),
-        timeout,
-    );
+    let result = start_interval(callback.as_ref().unchecked_ref(), timeout);
 
     callback.forget();
 
@@ -190,7 +366,7 @@ where
 ///
 /// # Example
 ///
-/// ```
+/// ```no_run
 /// use std::cell::Cell;
 ///
 /// use weblog::console_log;
@@ -219,16 +395,66 @@ pub fn clear_interval(timeout: i32) {
     let window = window().unwrap();
 
     window.clear_interval_with_handle(timeout);
+
+    debug_timer("clear_interval", timeout);
+}
+
+/// Like [`set_interval`], but also calls the `handler` once right away
+///
+/// Javascript's `setInterval()` waits one full interval before the first call. This function calls the `handler` synchronously before scheduling the interval,
+/// which is what you want for things like a clock, where the first tick shouldn't be delayed.
+///
+/// The first call happens even if scheduling the interval fails. Just like [`set_interval`], it returns the interval ID, which can be passed to [`clear_interval`].
+///
+///
+/// # Arguments
+///
+/// * `handler` - A Rust closure to be executed immediately and then every `timeout` milliseconds
+///
+/// * `timeout` - The execution interval in milliseconds
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use webru::{get_element_by_id, set_interval_immediate};
+///
+/// let clock = get_element_by_id("clock").unwrap();
+///
+/// // shows the time right away instead of after one second
+/// set_interval_immediate(
+///     move || {
+///         let time = js_sys::Date::new_0().to_locale_time_string("en-US");
+///         clock.set_text_content(Some(&String::from(time)));
+///     },
+///     1000,
+/// )
+/// .unwrap();
+/// ```
+pub fn set_interval_immediate<T>(handler: T, timeout: i32) -> Result<i32, JsValue>
+where
+    T: Fn() + 'static,
+{
+    handler();
+
+    set_interval(handler, timeout)
 }
 
 /// Combination of [`set_timeout()`] and [`clear_timeout()`] functions
 ///
+/// The timeout is stopped when the `Timeout` is dropped. Use [`Timeout::forget`] to keep it pending instead.
+///
 /// # Panics
 /// This function will panic if you try to call this outside of the web such as `node.js` runtime
 ///
 /// # Example
 ///
-/// ```rust
+/// ```no_run
 /// use std::cell::Cell;
 /// use std::rc::Rc;
 /// use webru::{set_timeout, Timeout};
@@ -252,7 +478,9 @@ pub fn clear_interval(timeout: i32) {
 ///             earth_shape.set(EarthShape::Round);
 ///         },
 ///         4000, // 4 secs
-///     );
+///     )
+///     // keep it pending after the `Timeout` is dropped
+///     .forget();
 /// }
 ///
 /// set_timeout(
@@ -292,9 +520,10 @@ pub fn clear_interval(timeout: i32) {
 /// .unwrap();
 ///
 /// ```
-#[derive(Clone)]
 pub struct Timeout {
     timeout_id: i32,
+    fired: Rc<Cell<bool>>,
+    stopped: Cell<bool>,
 }
 
 impl Timeout {
@@ -304,32 +533,170 @@ impl Timeout {
     ///
     /// After starting the timeout, you can stop it by calling the `stop` method.
     ///  
-    pub fn start<T: 'static>(handler: T, timeout: i32) -> Self
+    /// # Panics
+    ///
+    /// This method will panic if the timer couldn't be created. Use [`Timeout::try_start`] to handle that error.
+    ///
+    pub fn start<T>(handler: T, timeout: i32) -> Self
+    where
+        T: Fn() + 'static,
+    {
+        Self::try_start(handler, timeout).unwrap()
+    }
+
+    /// Starts the timeout without panicking.
+    ///
+    /// This method does the same thing as [`Timeout::start`], but returns the error from [`set_timeout`] instead of unwrapping it.
+    ///
+    pub fn try_start<T>(handler: T, timeout: i32) -> Result<Self, JsValue>
     where
-        T: Fn(),
+        T: Fn() + 'static,
     {
-        let timeout_id = set_timeout(handler, timeout).unwrap();
+        let fired = Rc::new(Cell::new(false));
 
-        Self { timeout_id }
+        let timeout_id = set_timeout(
+            {
+                let fired = Rc::clone(&fired);
+                move || {
+                    fired.set(true);
+                    handler();
+                }
+            },
+            timeout,
+        )?;
+
+        Ok(Self {
+            timeout_id,
+            fired,
+            stopped: Cell::new(false),
+        })
     }
 
     /// Stops the timeout.
     ///
     /// This method is equivalent to [`clear_timeout`] function.
+    /// The timeout is only cleared once, so stopping it again or dropping it afterwards does nothing.
     ///     
     pub fn stop(&self) {
+        if !self.fired.get() && !self.stopped.replace(true) {
+            clear_timeout(self.timeout_id);
+        }
+    }
+
+    /// Returns `true` if the handler has been called.
+    ///
+    /// Stopping a timeout which already fired does nothing, so this lets you know whether the handler ran before it was stopped.
+    ///
+    pub fn has_fired(&self) -> bool {
+        self.fired.get()
+    }
+
+    /// Keeps the timeout pending after the `Timeout` is dropped.
+    ///
+    /// Dropping a `Timeout` stops it. Call this method instead if you want to start a timeout and never stop it.
+    ///
+    pub fn forget(self) {
+        std::mem::forget(self);
+    }
+}
+
+impl Drop for Timeout {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+/// Like [`set_timeout`], but without leaking the closure
+///
+/// [`set_timeout`] calls `.forget()` on its [`Closure`], so the Rust closure is never freed, even after it ran.
+/// That's fine for a few timers, but it adds up in apps which schedule many short-lived timeouts.
+///
+/// This function returns a [`ManagedTimeout`] which owns the `Closure` instead. When it's dropped, the timeout is cleared and the closure is freed.
+/// The tradeoff is that you must keep the `ManagedTimeout` alive until the timeout fires: it's not fire-and-forget anymore.
+///
+///
+/// # Arguments
+///
+/// * `handler` - A Rust closure to be executed after the timer expires
+///
+/// * `timeout` - The time, in milliseconds that the timer should wait before the `handler` is executed
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use std::cell::RefCell;
+/// use weblog::console_log;
+/// use webru::{set_timeout_owned, ManagedTimeout};
+///
+/// thread_local! {
+///     static SAVE_TIMEOUT: RefCell<Option<ManagedTimeout>> = RefCell::new(None);
+/// }
+///
+/// // called on every keystroke, only saves 500ms after the last one
+/// fn schedule_save() {
+///     let timeout = set_timeout_owned(|| console_log!("Saving..."), 500).unwrap();
+///
+///     // dropping the previous timeout cancels it and frees its closure
+///     SAVE_TIMEOUT.with(|slot| *slot.borrow_mut() = Some(timeout));
+/// }
+/// ```
+///
+/// [`Closure`]: <https://docs.rs/wasm-bindgen/0.2.79/wasm_bindgen/closure/struct.Closure.html>
+pub fn set_timeout_owned<T>(handler: T, timeout: i32) -> Result<ManagedTimeout, JsValue>
+where
+    T: Fn() + 'static,
+{
+    let closure = Closure::wrap(Box::new(handler) as Box<dyn Fn()>);
+
+    let timeout_id = start_timeout(closure.as_ref().unchecked_ref(), timeout)?;
+
+    Ok(ManagedTimeout {
+        timeout_id,
+        _closure: closure,
+    })
+}
+
+/// A timeout created by [`set_timeout_owned`]
+///
+/// It owns the closure of the timeout. Dropping it clears the timeout (if it hasn't fired yet) and frees the closure.
+pub struct ManagedTimeout {
+    timeout_id: i32,
+    _closure: Closure<dyn Fn()>,
+}
+
+impl ManagedTimeout {
+    /// Returns the ID of the timeout.
+    ///
+    /// The ID can be passed to [`clear_timeout`], but the closure is only freed when the `ManagedTimeout` is dropped.
+    ///
+    pub fn id(&self) -> i32 {
+        self.timeout_id
+    }
+}
+
+impl Drop for ManagedTimeout {
+    fn drop(&mut self) {
+        // clearing a timeout which already fired does nothing
         clear_timeout(self.timeout_id);
     }
 }
 
 /// Combination of [`set_interval()`] and [`clear_interval()`] functions
 ///
+/// The interval is stopped when the `Interval` is dropped. Use [`Interval::forget`] to keep it running instead.
+///
 /// # Panics
 /// This function will panic if you try to call this outside of the web such as `node.js` runtime
 ///
 /// # Example
 ///
-/// ```rust
+/// ```no_run
 /// use wasm_bindgen::JsCast;
 /// use web_sys::HtmlElement;
 /// use weblog::console_log;
@@ -381,9 +748,9 @@ impl Interval {
     ///
     /// After starting the interval, you can stop it by calling the `stop` method.
     ///
-    pub fn start<T: 'static>(handler: T, timeout: i32) -> Self
+    pub fn start<T>(handler: T, timeout: i32) -> Self
     where
-        T: Fn(),
+        T: Fn() + 'static,
     {
         let interval_id = set_interval(handler, timeout).unwrap();
 
@@ -397,4 +764,365 @@ impl Interval {
     pub fn stop(&self) {
         clear_interval(self.interval_id);
     }
+
+    /// Keeps the interval running for the lifetime of the page.
+    ///
+    /// Dropping an `Interval` stops it. Call this method instead if you want to start an interval and never stop it.
+    ///
+    pub fn forget(self) {
+        std::mem::forget(self);
+    }
+}
+
+impl Drop for Interval {
+    fn drop(&mut self) {
+        clear_interval(self.interval_id);
+    }
+}
+
+/// Repeatedly call a closure with a randomized delay between the calls
+///
+/// Each delay is `base_ms` plus or minus a random amount of up to `jitter_ms` milliseconds.
+/// It's implemented with a chain of [`set_timeout`] calls, so every tick gets a new random delay.
+///
+/// When many clients poll a server with the same fixed interval, their requests tend to synchronize and hit the server at the same time.
+/// Randomizing the interval spreads them out.
+///
+/// The returned [`IntervalHandle`] stops the ticks when it's dropped or when its `stop` method is called.
+///
+///
+/// # Arguments
+///
+/// * `handler` - The Rust closure to execute on every tick
+///
+/// * `base_ms` - The average delay between the ticks in milliseconds
+///
+/// * `jitter_ms` - The maximum random deviation from `base_ms` in milliseconds. Negative delays are clamped to `0`
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use weblog::console_log;
+/// use webru::{set_interval_jittered, IntervalHandle};
+///
+/// // poll every 4 to 6 seconds
+/// let polling: IntervalHandle = set_interval_jittered(
+///     || {
+///         console_log!("Checking for new messages");
+///     },
+///     5000,
+///     1000,
+/// );
+/// ```
+pub fn set_interval_jittered<T>(handler: T, base_ms: i32, jitter_ms: i32) -> IntervalHandle
+where
+    T: FnMut() + 'static,
+{
+    let state = Rc::new(JitteredState {
+        timeout_id: Cell::new(None),
+        stopped: Cell::new(false),
+        tick: RefCell::new(None),
+        base_ms,
+        jitter_ms,
+    });
+
+    let tick = Closure::wrap(Box::new({
+        let state = Rc::downgrade(&state);
+        let mut handler = handler;
+
+        move || {
+            handler();
+
+            // the handle might have been stopped or dropped inside the `handler`
+            if let Some(state) = state.upgrade() {
+                state.schedule();
+            }
+        }
+    }) as Box<dyn FnMut()>);
+
+    *state.tick.borrow_mut() = Some(tick);
+    state.schedule();
+
+    IntervalHandle { state }
+}
+
+/// A handle to the ticks started by [`set_interval_jittered`]
+///
+/// The ticks are stopped when the handle is dropped. To stop the ticks from inside the handler, call [`stop`](IntervalHandle::stop) instead of dropping the handle there.
+pub struct IntervalHandle {
+    state: Rc<JitteredState>,
+}
+
+impl IntervalHandle {
+    /// Stops the ticks.
+    ///
+    /// This method is equivalent to dropping the handle, but it can also be called through a shared reference.
+    ///
+    pub fn stop(&self) {
+        self.state.stopped.set(true);
+
+        if let Some(timeout_id) = self.state.timeout_id.take() {
+            clear_timeout(timeout_id);
+        }
+    }
+}
+
+impl Drop for IntervalHandle {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+struct JitteredState {
+    timeout_id: Cell<Option<i32>>,
+    stopped: Cell<bool>,
+    tick: RefCell<Option<Closure<dyn FnMut()>>>,
+    base_ms: i32,
+    jitter_ms: i32,
+}
+
+impl JitteredState {
+    /// Schedules the next tick with a new random delay
+    fn schedule(&self) {
+        if self.stopped.get() {
+            return;
+        }
+
+        let deviation = (Math::random() * 2.0 - 1.0) * self.jitter_ms as f64;
+        let delay = (self.base_ms as f64 + deviation).round().max(0.0) as i32;
+
+        if let Some(tick) = self.tick.borrow().as_ref() {
+            let timeout_id = start_timeout(tick.as_ref().unchecked_ref(), delay).unwrap();
+
+            self.timeout_id.set(Some(timeout_id));
+        }
+    }
+}
+
+/// Call a closure when the user has been inactive for a while
+///
+/// An `IdleTimer` listens to the `mousemove`, `mousedown`, `keydown`, `scroll` and `touchstart` events of the window.
+/// Each of these events restarts an internal timeout, and when no activity happens for `timeout_ms` milliseconds, `on_idle` is called.
+/// After that, the next activity starts a new idle period.
+///
+/// The listeners and the pending timeout are removed when the `IdleTimer` is dropped or when the `stop` method is called.
+///
+/// # Panics
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+/// # Example
+///
+/// ```no_run
+/// use weblog::console_log;
+/// use webru::IdleTimer;
+///
+/// // warn the user after 5 minutes of inactivity
+/// let idle_timer: IdleTimer = IdleTimer::new(5 * 60 * 1000, || {
+///     console_log!("Are you still there?");
+/// });
+/// ```
+pub struct IdleTimer {
+    state: Rc<IdleState>,
+    _listeners: Vec<EventListenerHandle>,
+}
+
+struct IdleState {
+    timeout_id: Cell<Option<i32>>,
+    timeout_ms: i32,
+    on_idle: Closure<dyn Fn()>,
+}
+
+impl IdleState {
+    /// Restarts the idle period
+    fn reset(&self) {
+        if let Some(timeout_id) = self.timeout_id.take() {
+            clear_timeout(timeout_id);
+        }
+
+        let timeout_id =
+            start_timeout(self.on_idle.as_ref().unchecked_ref(), self.timeout_ms).unwrap();
+
+        self.timeout_id.set(Some(timeout_id));
+    }
+}
+
+impl IdleTimer {
+    /// Starts watching the user's activity.
+    ///
+    /// The first idle period starts immediately.
+    ///
+    pub fn new<T>(timeout_ms: i32, on_idle: T) -> Self
+    where
+        T: Fn() + 'static,
+    {
+        let state = Rc::new(IdleState {
+            timeout_id: Cell::new(None),
+            timeout_ms,
+            on_idle: Closure::wrap(Box::new(on_idle) as Box<dyn Fn()>),
+        });
+
+        let window = window().unwrap();
+
+        let listeners = ["mousemove", "mousedown", "keydown", "scroll", "touchstart"]
+            .iter()
+            .map(|event| {
+                let state = Rc::clone(&state);
+                listen(&window, event, move |_| state.reset())
+            })
+            .collect();
+
+        state.reset();
+
+        Self {
+            state,
+            _listeners: listeners,
+        }
+    }
+
+    /// Stops watching the user's activity.
+    ///
+    /// This is the same as dropping the `IdleTimer`.
+    ///
+    pub fn stop(self) {
+        drop(self);
+    }
+}
+
+impl Drop for IdleTimer {
+    fn drop(&mut self) {
+        if let Some(timeout_id) = self.state.timeout_id.take() {
+            clear_timeout(timeout_id);
+        }
+    }
+}
+
+/// Call a closure every second until a countdown reaches zero
+///
+/// `on_tick` is called once per second with the remaining seconds, from `seconds - 1` down to `0`.
+/// Right after the last tick, the internal interval is cleared and `on_done` is called. `on_done` is called exactly once, or never if the countdown is stopped before.
+///
+/// The countdown is stopped when the `Countdown` is dropped or when the `stop` method is called, so keep it alive until it's done.
+/// Use [`Countdown::forget`] to let it run to the end without keeping it.
+///
+/// # Panics
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+/// # Example
+///
+/// ```no_run
+/// use webru::{get_element_by_id, Countdown};
+///
+/// let label = get_element_by_id("resend-label").unwrap();
+///
+/// Countdown::start(
+///     30,
+///     {
+///         let label = label.clone();
+///         move |remaining| {
+///             let msg = format!("Resend the code in {}s", remaining);
+///             label.set_text_content(Some(&msg));
+///         }
+///     },
+///     move || label.set_text_content(Some("Resend the code")),
+/// )
+/// // dropping the countdown would stop it
+/// .forget();
+/// ```
+pub struct Countdown {
+    state: Rc<CountdownState>,
+    _tick: Closure<dyn Fn()>,
+}
+
+struct CountdownState {
+    interval_id: Cell<Option<i32>>,
+    remaining: Cell<u32>,
+    on_done: RefCell<Option<Box<dyn FnOnce()>>>,
+}
+
+impl CountdownState {
+    /// Clears the interval if it's still running
+    fn clear(&self) {
+        if let Some(interval_id) = self.interval_id.take() {
+            clear_interval(interval_id);
+        }
+    }
+}
+
+impl Countdown {
+    /// Starts the countdown.
+    ///
+    /// The first tick happens after one second. If `seconds` is `0`, `on_done` is called immediately.
+    ///
+    pub fn start<T1, T2>(seconds: u32, on_tick: T1, on_done: T2) -> Self
+    where
+        T1: Fn(u32) + 'static,
+        T2: FnOnce() + 'static,
+    {
+        let state = Rc::new(CountdownState {
+            interval_id: Cell::new(None),
+            remaining: Cell::new(seconds),
+            on_done: RefCell::new(Some(Box::new(on_done))),
+        });
+
+        let tick = Closure::wrap(Box::new({
+            let state = Rc::clone(&state);
+            move || {
+                let remaining = state.remaining.get().saturating_sub(1);
+                state.remaining.set(remaining);
+
+                on_tick(remaining);
+
+                if remaining == 0 {
+                    state.clear();
+
+                    let on_done = state.on_done.borrow_mut().take();
+                    if let Some(on_done) = on_done {
+                        on_done();
+                    }
+                }
+            }
+        }) as Box<dyn Fn()>);
+
+        if seconds == 0 {
+            let on_done = state.on_done.borrow_mut().take();
+            if let Some(on_done) = on_done {
+                on_done();
+            }
+        } else {
+            let interval_id = start_interval(tick.as_ref().unchecked_ref(), 1000).unwrap();
+
+            state.interval_id.set(Some(interval_id));
+        }
+
+        Self { state, _tick: tick }
+    }
+
+    /// Stops the countdown without calling `on_done`.
+    ///
+    /// Dropping the `Countdown` does the same.
+    ///
+    pub fn stop(&self) {
+        self.state.clear();
+        self.state.on_done.borrow_mut().take();
+    }
+
+    /// Keeps the countdown running after the `Countdown` is dropped.
+    ///
+    /// Dropping a `Countdown` stops it. Call this method instead if you want it to run until `on_done` is called, without keeping it around.
+    ///
+    pub fn forget(self) {
+        std::mem::forget(self);
+    }
+}
+
+impl Drop for Countdown {
+    fn drop(&mut self) {
+        self.stop();
+    }
 }
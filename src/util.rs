@@ -0,0 +1,106 @@
+use js_sys::Math;
+
+use std::cell::Cell;
+
+thread_local! {
+    /// Counter used by [`unique_id`]
+    static ID_COUNTER: Cell<u64> = const { Cell::new(0) };
+}
+
+/// Generate an id which is unique for the page session
+///
+/// The id has the format `prefix-<counter>`, where the counter increases on every call.
+/// It's useful for wiring up attributes like `for` or `aria-labelledby` on dynamically created elements.
+///
+/// Note that the id is only unique among the ids generated by this function. It doesn't check the ids already used in the document.
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use webru::{create_element, unique_id};
+///
+/// let id = unique_id("email");
+///
+/// let label = create_element("label");
+/// label.set_attribute("for", &id).unwrap();
+///
+/// let input = create_element("input");
+/// input.set_id(&id);
+/// ```
+pub fn unique_id(prefix: &str) -> String {
+    let counter = ID_COUNTER.with(|counter| {
+        let value = counter.get();
+        counter.set(value + 1);
+        value
+    });
+
+    format!("{}-{}", prefix, counter)
+}
+
+/// Generate a random id
+///
+/// The id has the format `prefix-<random>`, where `<random>` is 8 random hexadecimal digits from javascript's [`Math.random()`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/random) method, like `tooltip-3f9a2c1e`.
+///
+/// Unlike [`unique_id`], the id doesn't depend on the order of the calls, so it's also unlikely to clash with ids generated in another page load, for example ids saved in `localStorage`.
+///
+/// Collisions are unlikely for the number of elements of a typical page, but they are possible, and the ids are not cryptographically random. Use [`unique_id`] if the ids must be unique within the page.
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use webru::{create_element, random_id};
+///
+/// let id = random_id("tooltip");
+///
+/// let tooltip = create_element("div");
+/// tooltip.set_id(&id);
+///
+/// let button = create_element("button");
+/// button.set_attribute("aria-describedby", &id).unwrap();
+/// ```
+pub fn random_id(prefix: &str) -> String {
+    let random = (Math::random() * (u32::MAX as f64 + 1.0)) as u32;
+
+    format!("{}-{:08x}", prefix, random)
+}
+
+/// Format a number of bytes into a human-readable size
+///
+/// Sizes under 1 KB are shown in bytes. Larger sizes are shown in `KB`, `MB` or `GB` with one decimal.
+/// The units are powers of 1024, like in most file managers.
+///
+///
+/// # Example
+///
+/// ```
+/// use webru::format_bytes;
+///
+/// assert_eq!(format_bytes(512), "512 B");
+/// assert_eq!(format_bytes(1536), "1.5 KB");
+/// assert_eq!(format_bytes(5 * 1024 * 1024), "5.0 MB");
+/// ```
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 3] = ["KB", "MB", "GB"];
+
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+
+    // `size` is compared after rounding, so 1048575 bytes becomes "1.0 MB" instead of "1024.0 KB"
+    while (size * 10.0).round() / 10.0 >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+
+    format!("{:.1} {}", size, UNITS[unit])
+}
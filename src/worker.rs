@@ -0,0 +1,94 @@
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{MessageEvent, Worker};
+
+/// A handle to a [`Web Worker`](https://developer.mozilla.org/en-US/docs/Web/API/Web_Workers_API) started by [`spawn_worker`]
+///
+/// The worker is terminated when the handle is dropped.
+pub struct WorkerHandle {
+    worker: Worker,
+    _on_message: Closure<dyn FnMut(MessageEvent)>,
+}
+
+impl WorkerHandle {
+    /// Sends a message to the worker.
+    ///
+    /// This method is equivalent to javascript's [`Worker.postMessage()`](https://developer.mozilla.org/en-US/docs/Web/API/Worker/postMessage) method.
+    /// The worker receives a [structured clone](https://developer.mozilla.org/en-US/docs/Web/API/Web_Workers_API/Structured_clone_algorithm) of `msg`.
+    ///
+    /// It returns `Err` if the message can't be cloned
+    ///
+    pub fn post_message(&self, msg: &JsValue) -> Result<(), JsValue> {
+        self.worker.post_message(msg)
+    }
+
+    /// Gets the underlying [`web_sys::Worker`](https://docs.rs/web-sys/0.3.56/web_sys/struct.Worker.html) object.
+    ///
+    pub fn worker(&self) -> &Worker {
+        &self.worker
+    }
+}
+
+impl Drop for WorkerHandle {
+    fn drop(&mut self) {
+        self.worker.set_onmessage(None);
+        self.worker.terminate();
+    }
+}
+
+/// Start a [`Web Worker`](https://developer.mozilla.org/en-US/docs/Web/API/Web_Workers_API) and listen to its messages
+///
+/// Web workers run a script in a background thread, so heavy work doesn't freeze the page.
+/// The `on_message` closure is called with the `data` of every message the worker sends with `postMessage()`.
+///
+/// The worker is terminated when the returned [`WorkerHandle`] is dropped.
+///
+/// It returns `Err` if the worker couldn't be created, for example if the `script_url` is not of the same origin as the page
+///
+///
+/// # Arguments
+///
+/// * `script_url` - The url of the worker's javascript file
+///
+/// * `on_message` - The Rust closure to call with the data of every message from the worker
+///
+///
+/// # Panics
+///
+/// This function will panic if you try to call this outside of the web such as `node.js` runtime
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use wasm_bindgen::JsValue;
+/// use weblog::console_log;
+/// use webru::{spawn_worker, WorkerHandle};
+///
+/// // `/prime.js` contains: onmessage = (e) => postMessage(findPrimes(e.data));
+/// let worker: WorkerHandle = spawn_worker("/prime.js", |primes: JsValue| {
+///     console_log!("Primes: ", primes);
+/// })
+/// .unwrap();
+///
+/// worker.post_message(&JsValue::from(1_000_000)).unwrap();
+/// ```
+pub fn spawn_worker<T>(script_url: &str, on_message: T) -> Result<WorkerHandle, JsValue>
+where
+    T: FnMut(JsValue) + 'static,
+{
+    let worker = Worker::new(script_url)?;
+
+    let mut on_message = on_message;
+    let closure = Closure::wrap(
+        Box::new(move |event: MessageEvent| on_message(event.data()))
+            as Box<dyn FnMut(MessageEvent)>,
+    );
+
+    worker.set_onmessage(Some(closure.as_ref().unchecked_ref()));
+
+    Ok(WorkerHandle {
+        worker,
+        _on_message: closure,
+    })
+}
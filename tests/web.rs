@@ -385,6 +385,15 @@ fn prompt_test() {
     prompt("If you see this prompt, the test `prompt_test` has passed");
 }
 
+#[wasm_bindgen_test]
+fn confirm_test() {
+    // NOTE: You need to test it manually
+    let confirmed = confirm("Click `OK` if you see this dialog");
+
+    let msg = format!("confirmed: {}", confirmed);
+    console_log!(msg);
+}
+
 #[wasm_bindgen_test]
 fn set_timeout_clear_timeout_test() {
     // NOTE: You need to test it manually
@@ -455,7 +464,8 @@ fn set_timeout_clear_timeout_test() {
     let cb = callback({
         move || {
             let settimeout_id = Rc::clone(&settimeout_id.clone());
-            console_log!(format!("set timeout: {:?}", settimeout_id));
+            let msg = format!("set timeout: {:?}", settimeout_id);
+            console_log!(msg);
 
             if let Some(id) = settimeout_id.id.get() {
                 clear_timeout(id);
@@ -598,7 +608,8 @@ fn timeout_test() {
                 earth_shape.set(EarthShape::Round);
             },
             4000,
-        );
+        )
+        .forget();
     }
 
     set_timeout(
@@ -652,7 +663,8 @@ fn interval_test() {
                 earth_shape.set(EarthShape::Round);
             },
             4000,
-        );
+        )
+        .forget();
     }
 
     set_interval(
@@ -713,3 +725,2520 @@ fn media_query_text() {
         1000,
     );
 }
+
+#[wasm_bindgen_test]
+fn computed_color_test() {
+    let div = create_element("div")
+        .dyn_ref::<HtmlElement>()
+        .unwrap()
+        .clone();
+
+    // setting a known color and a semi-transparent background
+    div.style()
+        .set_property("color", "rgb(10, 20, 30)")
+        .unwrap();
+    div.style()
+        .set_property("background-color", "rgba(255, 0, 0, 0.5)")
+        .unwrap();
+
+    body().append_child(&div).unwrap();
+
+    assert_eq!(computed_color(&div, "color"), Some((10, 20, 30, 1.0)));
+    assert_eq!(
+        computed_color(&div, "background-color"),
+        Some((255, 0, 0, 0.5))
+    );
+
+    // `display` is not a color
+    assert_eq!(computed_color(&div, "display"), None);
+}
+
+#[wasm_bindgen_test]
+fn is_visible_element_test() {
+    // creates a 50x50 <div> with an extra inline style
+    let sized_div = |property: &str, value: &str| {
+        let div = create_element("div")
+            .dyn_ref::<HtmlElement>()
+            .unwrap()
+            .clone();
+
+        div.style().set_property("width", "50px").unwrap();
+        div.style().set_property("height", "50px").unwrap();
+        div.style().set_property(property, value).unwrap();
+
+        body().append_child(&div).unwrap();
+
+        div
+    };
+
+    let shown = sized_div("display", "block");
+    let display_none = sized_div("display", "none");
+    let visibility_hidden = sized_div("visibility", "hidden");
+
+    assert!(is_visible_element(&shown));
+    assert!(!is_visible_element(&display_none));
+    assert!(!is_visible_element(&visibility_hidden));
+}
+
+#[wasm_bindgen_test]
+async fn smooth_scroll_to_test() {
+    // making the page tall enough to be scrollable
+    let spacer = create_element("div")
+        .dyn_ref::<HtmlElement>()
+        .unwrap()
+        .clone();
+    spacer.style().set_property("height", "5000px").unwrap();
+    body().append_child(&spacer).unwrap();
+
+    smooth_scroll_to(300.0, 200.0).await;
+
+    let scroll_y = window().unwrap().scroll_y().unwrap();
+    assert!((scroll_y - 300.0).abs() < 1.0);
+
+    // scroll back to the top for the other tests
+    smooth_scroll_to(0.0, 0.0).await;
+
+    assert_eq!(window().unwrap().scroll_y().unwrap(), 0.0);
+}
+
+#[wasm_bindgen_test]
+fn focus_trap_test() {
+    use web_sys::{KeyboardEvent, KeyboardEventInit};
+
+    let container = create_element("div");
+    body().append_child(&container).unwrap();
+
+    // three focusable elements inside the container
+    let buttons: Vec<HtmlElement> = (0..3)
+        .map(|i| {
+            let button = create_element("button")
+                .dyn_ref::<HtmlElement>()
+                .unwrap()
+                .clone();
+            button.set_inner_html(&format!("button {}", i));
+            container.append_child(&button).unwrap();
+            button
+        })
+        .collect();
+
+    let press_tab = |target: &HtmlElement, shift: bool| {
+        let init = KeyboardEventInit::new();
+        init.set_key("Tab");
+        init.set_shift_key(shift);
+        init.set_bubbles(true);
+        init.set_cancelable(true);
+
+        let event = KeyboardEvent::new_with_keyboard_event_init_dict("keydown", &init).unwrap();
+        target.dispatch_event(&event).unwrap();
+    };
+
+    let active = || document().active_element().unwrap();
+
+    let trap = FocusTrap::activate(&container);
+
+    // Tab from the last element wraps to the first one
+    buttons[2].focus().unwrap();
+    press_tab(&buttons[2], false);
+    assert_eq!(active(), *buttons[0]);
+
+    // Shift+Tab from the first element wraps to the last one
+    press_tab(&buttons[0], true);
+    assert_eq!(active(), *buttons[2]);
+
+    trap.release();
+
+    // after releasing, the focus is not moved anymore
+    press_tab(&buttons[2], false);
+    assert_eq!(active(), *buttons[2]);
+}
+
+/// Resolves after `ms` milliseconds
+async fn sleep(ms: i32) {
+    let promise = js_sys::Promise::new(&mut |resolve, _| {
+        window()
+            .unwrap()
+            .set_timeout_with_callback_and_timeout_and_arguments_0(&resolve, ms)
+            .unwrap();
+    });
+
+    wasm_bindgen_futures::JsFuture::from(promise).await.unwrap();
+}
+
+#[wasm_bindgen_test]
+async fn timeout_try_start_test() {
+    let fired = Rc::new(Cell::new(false));
+
+    // a started timeout fires
+    let timeout = Timeout::try_start(
+        {
+            let fired = Rc::clone(&fired);
+            move || fired.set(true)
+        },
+        10,
+    );
+    assert!(timeout.is_ok());
+
+    sleep(50).await;
+    assert!(fired.get());
+
+    // a stopped timeout never fires
+    fired.set(false);
+
+    let timeout = Timeout::try_start(
+        {
+            let fired = Rc::clone(&fired);
+            move || fired.set(true)
+        },
+        10,
+    )
+    .unwrap();
+    timeout.stop();
+
+    sleep(50).await;
+    assert!(!fired.get());
+
+    // a dropped timeout never fires either
+    {
+        let fired = Rc::clone(&fired);
+        let _timeout = Timeout::try_start(move || fired.set(true), 10).unwrap();
+    }
+
+    sleep(50).await;
+    assert!(!fired.get());
+}
+
+#[wasm_bindgen_test]
+fn content_size_scroll_size_test() {
+    let outer = create_element("div")
+        .dyn_ref::<HtmlElement>()
+        .unwrap()
+        .clone();
+    outer.style().set_property("width", "100px").unwrap();
+    outer.style().set_property("height", "50px").unwrap();
+    outer.style().set_property("overflow", "auto").unwrap();
+
+    // the inner element is taller than the outer one
+    let inner = create_element("div")
+        .dyn_ref::<HtmlElement>()
+        .unwrap()
+        .clone();
+    inner.style().set_property("height", "200px").unwrap();
+
+    outer.append_child(&inner).unwrap();
+    body().append_child(&outer).unwrap();
+
+    let (width, height) = content_size(&outer);
+    assert!((0.0..=100.0).contains(&width));
+    assert_eq!(height, 50.0);
+
+    let (scroll_width, scroll_height) = scroll_size(&outer);
+    assert!(scroll_width >= 0.0);
+    assert_eq!(scroll_height, 200.0);
+}
+
+#[wasm_bindgen_test]
+async fn load_image_test() {
+    // a 1x1 transparent gif
+    const PIXEL: &str =
+        "data:image/gif;base64,R0lGODlhAQABAIAAAAAAAP///yH5BAEAAAAALAAAAAABAAEAAAIBRAA7";
+
+    let image = load_image(PIXEL).await.unwrap();
+
+    assert!(image.complete());
+    assert_eq!(image.natural_width(), 1);
+
+    // a broken image is an error
+    assert!(load_image("data:image/gif;base64,broken").await.is_err());
+}
+
+#[wasm_bindgen_test]
+fn toggle_class_if_test() {
+    let div = create_element("div");
+
+    toggle_class_if(&div, "active", true);
+    assert!(div.class_list().contains("active"));
+
+    // adding it again doesn't duplicate the class
+    toggle_class_if(&div, "active", true);
+    assert_eq!(div.class_name(), "active");
+
+    toggle_class_if(&div, "active", false);
+    assert!(!div.class_list().contains("active"));
+}
+
+#[wasm_bindgen_test]
+fn add_classes_test() {
+    let div = create_element("div");
+
+    add_classes(&div, &["card", "shadow", "rounded"]);
+    assert_eq!(div.class_name(), "card shadow rounded");
+
+    // an empty slice is a no-op
+    add_classes(&div, &[]);
+    remove_classes(&div, &[]);
+    assert_eq!(div.class_name(), "card shadow rounded");
+
+    remove_classes(&div, &["shadow", "rounded", "missing"]);
+    assert_eq!(div.class_name(), "card");
+}
+
+#[wasm_bindgen_test]
+fn forms_test() {
+    let before = forms().len();
+
+    let form1 = create_element("form");
+    form1.set_id("forms-test-1");
+    let form2 = create_element("form");
+    form2.set_id("forms-test-2");
+
+    body().append_child(&form1).unwrap();
+    body().append_child(&form2).unwrap();
+
+    let forms = forms();
+
+    assert_eq!(forms.len(), before + 2);
+    assert!(forms.iter().any(|form| form.id() == "forms-test-1"));
+    assert!(forms.iter().any(|form| form.id() == "forms-test-2"));
+}
+
+#[wasm_bindgen_test]
+fn on_animation_end_on_transition_end_test() {
+    use web_sys::Event;
+
+    let div = create_element("div");
+    let animations = Rc::new(Cell::new(0));
+    let transitions = Rc::new(Cell::new(0));
+
+    let animation_listener = on_animation_end(&div, {
+        let animations = Rc::clone(&animations);
+        move || animations.set(animations.get() + 1)
+    });
+    let transition_listener = on_transition_end(&div, {
+        let transitions = Rc::clone(&transitions);
+        move || transitions.set(transitions.get() + 1)
+    });
+
+    div.dispatch_event(&Event::new("transitionend").unwrap())
+        .unwrap();
+    div.dispatch_event(&Event::new("animationend").unwrap())
+        .unwrap();
+
+    assert_eq!(transitions.get(), 1);
+    assert_eq!(animations.get(), 1);
+
+    // removing the listeners
+    animation_listener.remove();
+    drop(transition_listener);
+
+    div.dispatch_event(&Event::new("transitionend").unwrap())
+        .unwrap();
+    div.dispatch_event(&Event::new("animationend").unwrap())
+        .unwrap();
+
+    assert_eq!(transitions.get(), 1);
+    assert_eq!(animations.get(), 1);
+}
+
+#[wasm_bindgen_test]
+async fn wait_for_transition_test() {
+    let div = create_element("div")
+        .dyn_ref::<HtmlElement>()
+        .unwrap()
+        .clone();
+    div.style()
+        .set_property("transition", "opacity 50ms")
+        .unwrap();
+    body().append_child(&div).unwrap();
+
+    // forcing a layout so that the next style change is transitioned
+    div.offset_width();
+    div.style().set_property("opacity", "0").unwrap();
+
+    wait_for_transition(&div, Some(2000)).await;
+
+    assert_eq!(
+        window()
+            .unwrap()
+            .get_computed_style(&div)
+            .unwrap()
+            .unwrap()
+            .get_property_value("opacity")
+            .unwrap(),
+        "0"
+    );
+
+    // nothing is transitioning, so only the timeout resolves the future
+    wait_for_transition(&div, Some(10)).await;
+}
+
+#[wasm_bindgen_test]
+fn unique_id_test() {
+    let id1 = unique_id("field");
+    let id2 = unique_id("field");
+
+    assert_ne!(id1, id2);
+    assert!(id1.starts_with("field-"));
+    assert!(id2.starts_with("field-"));
+}
+
+#[wasm_bindgen_test]
+fn random_id_test() {
+    let id1 = random_id("tooltip");
+    let id2 = random_id("tooltip");
+
+    assert_ne!(id1, id2);
+    assert!(id1.starts_with("tooltip-"));
+    assert!(id2.starts_with("tooltip-"));
+    assert_eq!(id1.len(), "tooltip-".len() + 8);
+}
+
+#[wasm_bindgen_test]
+fn set_disabled_is_disabled_test() {
+    let button = create_element("button")
+        .dyn_ref::<HtmlElement>()
+        .unwrap()
+        .clone();
+    body().append_child(&button).unwrap();
+
+    assert!(!is_disabled(&button));
+
+    set_disabled(&button, true);
+    assert!(is_disabled(&button));
+    assert!(button
+        .dyn_ref::<web_sys::HtmlButtonElement>()
+        .unwrap()
+        .disabled());
+
+    set_disabled(&button, false);
+    assert!(!is_disabled(&button));
+
+    // an input inside a disabled <fieldset> is disabled too
+    let fieldset = create_element("fieldset")
+        .dyn_ref::<HtmlElement>()
+        .unwrap()
+        .clone();
+    let input = create_element("input")
+        .dyn_ref::<HtmlElement>()
+        .unwrap()
+        .clone();
+    fieldset.append_child(&input).unwrap();
+    body().append_child(&fieldset).unwrap();
+
+    set_disabled(&fieldset, true);
+    assert!(is_disabled(&input));
+}
+
+#[wasm_bindgen_test]
+fn insert_at_test() {
+    let list = create_element("ul");
+    list.set_inner_html("<li>b</li><li>d</li>");
+
+    let li = |text: &str| {
+        let li = create_element("li");
+        li.set_inner_html(text);
+        li
+    };
+
+    // at the start
+    insert_at(&list, &li("a"), 0);
+    // in the middle
+    insert_at(&list, &li("c"), 2);
+    // past the end
+    insert_at(&list, &li("e"), 100);
+
+    assert_eq!(
+        list.inner_html(),
+        "<li>a</li><li>b</li><li>c</li><li>d</li><li>e</li>"
+    );
+}
+
+#[wasm_bindgen_test]
+fn insert_at_reorder_test() {
+    let list = create_element("ul");
+    list.set_inner_html("<li>a</li><li>b</li><li>c</li>");
+
+    let item = |index: u32| list.children().item(index).unwrap();
+
+    // moving forward
+    insert_at(&list, &item(0), 2);
+    assert_eq!(list.inner_html(), "<li>b</li><li>c</li><li>a</li>");
+
+    // moving backward
+    insert_at(&list, &item(2), 1);
+    assert_eq!(list.inner_html(), "<li>b</li><li>a</li><li>c</li>");
+
+    // staying at the same index
+    insert_at(&list, &item(1), 1);
+    assert_eq!(list.inner_html(), "<li>b</li><li>a</li><li>c</li>");
+}
+
+#[wasm_bindgen_test]
+fn document_offset_test() {
+    let outer = create_element("div")
+        .dyn_ref::<HtmlElement>()
+        .unwrap()
+        .clone();
+    outer.style().set_property("position", "absolute").unwrap();
+    outer.style().set_property("left", "100px").unwrap();
+    outer.style().set_property("top", "200px").unwrap();
+
+    let inner = create_element("div")
+        .dyn_ref::<HtmlElement>()
+        .unwrap()
+        .clone();
+    inner.style().set_property("position", "relative").unwrap();
+    inner.style().set_property("left", "10px").unwrap();
+    inner.style().set_property("top", "20px").unwrap();
+
+    outer.append_child(&inner).unwrap();
+    body().append_child(&outer).unwrap();
+
+    assert_eq!(document_offset(&outer), (100.0, 200.0));
+    assert_eq!(document_offset(&inner), (110.0, 220.0));
+}
+
+#[wasm_bindgen_test]
+fn set_smooth_scroll_test() {
+    let scroll_behavior = || {
+        window()
+            .unwrap()
+            .get_computed_style(&document().document_element().unwrap())
+            .unwrap()
+            .unwrap()
+            .get_property_value("scroll-behavior")
+            .unwrap()
+    };
+
+    set_smooth_scroll(true);
+    assert_eq!(scroll_behavior(), "smooth");
+
+    set_smooth_scroll(false);
+    assert_eq!(scroll_behavior(), "auto");
+}
+
+#[wasm_bindgen_test]
+fn copy_to_clipboard_compat_test() {
+    // NOTE: You need to test it manually, ideally on an `http://` origin other than localhost
+
+    const TEXT: &str = "Copied by webru";
+
+    let button = create_element("button")
+        .dyn_ref::<HtmlElement>()
+        .unwrap()
+        .clone();
+    button.set_inner_html("Copy to clipboard");
+
+    let callback = callback(|| {
+        wasm_bindgen_futures::spawn_local(async {
+            copy_to_clipboard_compat(TEXT).await.unwrap();
+            console_log!(
+                "Copied. Paste it somewhere, if you see `",
+                TEXT,
+                "` the test `copy_to_clipboard_compat_test` has passed"
+            );
+        });
+    });
+
+    button.set_onclick(Some(callback.as_ref().dyn_ref().unwrap()));
+
+    callback.forget();
+
+    body().append_child(&button).unwrap();
+}
+
+#[wasm_bindgen_test]
+fn charset_test() {
+    let charset = charset();
+
+    console_log!(
+        "The value of `charset()` for your website is: ",
+        charset.clone()
+    );
+
+    assert!(!charset.is_empty());
+    assert_eq!(charset, document().character_set());
+}
+
+#[wasm_bindgen_test]
+fn referrer_test() {
+    // In the test runner the page is usually opened directly, so this is most likely an empty string
+    console_log!(
+        "The value of `referrer()` for your website is: ",
+        referrer()
+    );
+
+    assert_eq!(referrer(), document().referrer());
+}
+
+#[wasm_bindgen_test]
+fn on_before_unload_test() {
+    let calls = Rc::new(Cell::new(0));
+
+    let guard = on_before_unload({
+        let calls = Rc::clone(&calls);
+        move || {
+            calls.set(calls.get() + 1);
+            false
+        }
+    });
+
+    // a synthetic event only calls the handler, it can't show the dialog
+    let event = web_sys::Event::new("beforeunload").unwrap();
+    window().unwrap().dispatch_event(&event).unwrap();
+    assert_eq!(calls.get(), 1);
+
+    guard.remove();
+
+    window().unwrap().dispatch_event(&event).unwrap();
+    assert_eq!(calls.get(), 1);
+}
+
+#[wasm_bindgen_test]
+async fn set_interval_jittered_test() {
+    const BASE: f64 = 60.0;
+    const JITTER: f64 = 20.0;
+
+    let ticks: Rc<std::cell::RefCell<Vec<f64>>> = Rc::new(std::cell::RefCell::new(Vec::new()));
+
+    let handle = set_interval_jittered(
+        {
+            let ticks = Rc::clone(&ticks);
+            move || ticks.borrow_mut().push(js_sys::Date::now())
+        },
+        BASE as i32,
+        JITTER as i32,
+    );
+
+    sleep(500).await;
+    handle.stop();
+
+    let recorded = ticks.borrow().clone();
+    assert!(recorded.len() >= 3);
+
+    for pair in recorded.windows(2) {
+        let delay = pair[1] - pair[0];
+
+        // timers are never early (give or take a millisecond of rounding), but they can be late
+        assert!(delay >= BASE - JITTER - 2.0, "delay {} is too short", delay);
+        assert!(delay <= BASE + JITTER + 50.0, "delay {} is too long", delay);
+    }
+
+    // no more ticks after stopping
+    drop(handle);
+    sleep(200).await;
+    assert_eq!(recorded.len(), ticks.borrow().len());
+}
+
+#[wasm_bindgen_test]
+fn computed_styles_test() {
+    let div = create_element("div")
+        .dyn_ref::<HtmlElement>()
+        .unwrap()
+        .clone();
+    div.style().set_property("color", "rgb(1, 2, 3)").unwrap();
+    div.style().set_property("display", "flex").unwrap();
+    body().append_child(&div).unwrap();
+
+    let styles = computed_styles(&div, &["color", "display"]);
+
+    assert_eq!(styles.len(), 2);
+    assert_eq!(styles["color"], "rgb(1, 2, 3)");
+    assert_eq!(styles["display"], "flex");
+}
+
+#[wasm_bindgen_test]
+fn select_input_text_test() {
+    use web_sys::HtmlInputElement;
+
+    let input = create_element("input")
+        .dyn_into::<HtmlInputElement>()
+        .unwrap();
+    body().append_child(&input).unwrap();
+
+    input.set_value("Hello world");
+
+    select_input_text(&input);
+    assert_eq!(input.selection_start().unwrap(), Some(0));
+    assert_eq!(input.selection_end().unwrap(), Some(11));
+
+    set_selection_range(&input, 6, 11);
+    assert_eq!(input.selection_start().unwrap(), Some(6));
+    assert_eq!(input.selection_end().unwrap(), Some(11));
+}
+
+#[wasm_bindgen_test]
+fn path_segments_test() {
+    let history = window().unwrap().history().unwrap();
+    let original = url();
+
+    let segments_of = |path: &str| {
+        history
+            .replace_state_with_url(&wasm_bindgen::JsValue::NULL, "", Some(path))
+            .unwrap();
+        path_segments()
+    };
+
+    assert_eq!(segments_of("/"), Vec::<String>::new());
+    assert_eq!(segments_of("/users/42/edit"), vec!["users", "42", "edit"]);
+    assert_eq!(segments_of("/users/42/"), vec!["users", "42"]);
+    assert_eq!(segments_of("/users//42"), vec!["users", "42"]);
+
+    // restoring the url for the other tests
+    history
+        .replace_state_with_url(&wasm_bindgen::JsValue::NULL, "", Some(&original))
+        .unwrap();
+}
+
+#[wasm_bindgen_test]
+fn on_storage_change_test() {
+    use web_sys::{StorageEvent, StorageEventInit};
+
+    let changes: Rc<std::cell::RefCell<Vec<StorageChange>>> =
+        Rc::new(std::cell::RefCell::new(Vec::new()));
+
+    let listener = on_storage_change({
+        let changes = Rc::clone(&changes);
+        move |change| changes.borrow_mut().push(change)
+    });
+
+    // simulating a change made by another tab
+    let init = StorageEventInit::new();
+    init.set_key(Some("theme"));
+    init.set_old_value(Some("light"));
+    init.set_new_value(Some("dark"));
+
+    let event = StorageEvent::new_with_event_init_dict("storage", &init).unwrap();
+    window().unwrap().dispatch_event(&event).unwrap();
+
+    assert_eq!(
+        *changes.borrow(),
+        vec![StorageChange {
+            key: Some("theme".to_string()),
+            old_value: Some("light".to_string()),
+            new_value: Some("dark".to_string()),
+        }]
+    );
+
+    listener.remove();
+}
+
+#[wasm_bindgen_test]
+async fn spawn_worker_test() {
+    use wasm_bindgen::JsValue;
+    use web_sys::{Blob, BlobPropertyBag, Url};
+
+    // an echo worker
+    let source = js_sys::Array::of1(&JsValue::from_str(
+        "onmessage = (event) => postMessage(event.data);",
+    ));
+    let options = BlobPropertyBag::new();
+    options.set_type("application/javascript");
+    let blob = Blob::new_with_str_sequence_and_options(&source, &options).unwrap();
+    let script_url = Url::create_object_url_with_blob(&blob).unwrap();
+
+    let received: Rc<std::cell::RefCell<Option<JsValue>>> = Rc::new(std::cell::RefCell::new(None));
+
+    let worker = spawn_worker(&script_url, {
+        let received = Rc::clone(&received);
+        move |data| *received.borrow_mut() = Some(data)
+    })
+    .unwrap();
+
+    worker.post_message(&JsValue::from_str("ping")).unwrap();
+
+    // waiting up to 2 seconds for the echo
+    for _ in 0..40 {
+        if received.borrow().is_some() {
+            break;
+        }
+        sleep(50).await;
+    }
+
+    assert_eq!(received.borrow().clone(), Some(JsValue::from_str("ping")));
+
+    drop(worker);
+    Url::revoke_object_url(&script_url).unwrap();
+}
+
+#[wasm_bindgen_test]
+async fn idle_timer_test() {
+    let idle_count = Rc::new(Cell::new(0));
+
+    let idle_timer = IdleTimer::new(150, {
+        let idle_count = Rc::clone(&idle_count);
+        move || idle_count.set(idle_count.get() + 1)
+    });
+
+    // simulating activity every 50ms for 500ms
+    for _ in 0..10 {
+        window()
+            .unwrap()
+            .dispatch_event(&web_sys::Event::new("keydown").unwrap())
+            .unwrap();
+        sleep(50).await;
+    }
+
+    assert_eq!(idle_count.get(), 0);
+
+    // quiescence
+    sleep(300).await;
+    assert_eq!(idle_count.get(), 1);
+
+    // after stopping, activity doesn't start a new idle period
+    idle_timer.stop();
+    window()
+        .unwrap()
+        .dispatch_event(&web_sys::Event::new("keydown").unwrap())
+        .unwrap();
+    sleep(300).await;
+    assert_eq!(idle_count.get(), 1);
+}
+
+#[wasm_bindgen_test]
+fn require_element_by_id_test() {
+    const ID: &str = "required-element";
+
+    // absent
+    let error = require_element_by_id(ID).unwrap_err();
+    assert_eq!(
+        error.as_string().unwrap(),
+        format!("no element with id '{}'", ID)
+    );
+
+    // present
+    let p = create_element("p");
+    p.set_id(ID);
+    body().append_child(&p).unwrap();
+
+    assert_eq!(require_element_by_id(ID), Ok(p));
+}
+
+#[wasm_bindgen_test]
+fn append_children_test() {
+    use web_sys::Node;
+
+    const COUNT: usize = 500;
+
+    let list = create_element("ul");
+
+    let items: Vec<Node> = (0..COUNT)
+        .map(|i| {
+            let li = create_element("li");
+            li.set_inner_html(&i.to_string());
+            li.into()
+        })
+        .collect();
+    let items: Vec<&Node> = items.iter().collect();
+
+    append_children(&list, &items).unwrap();
+
+    assert_eq!(list.child_element_count() as usize, COUNT);
+    assert_eq!(list.first_element_child().unwrap().inner_html(), "0");
+    assert_eq!(
+        list.last_element_child().unwrap().inner_html(),
+        (COUNT - 1).to_string()
+    );
+
+    // appending an ancestor is an error and leaves the parent untouched
+    let child = create_element("div");
+    assert!(append_children(&list, &[&child, &list]).is_err());
+    assert_eq!(list.child_element_count() as usize, COUNT);
+}
+
+#[wasm_bindgen_test]
+fn append_children_ancestor_test() {
+    let outer = create_element("div");
+    let list = create_element("ul");
+    outer.append_child(&list).unwrap();
+    body().append_child(&outer).unwrap();
+
+    let html = body().inner_html();
+    let child = create_element("li");
+
+    assert!(append_children(&list, &[&child, &outer]).is_err());
+
+    // nothing was moved out of the document
+    assert_eq!(outer.parent_element(), Some(body().into()));
+    assert_eq!(list.parent_element().as_ref(), Some(&outer));
+    assert!(child.parent_node().is_none());
+    assert_eq!(body().inner_html(), html);
+
+    outer.remove();
+}
+
+#[wasm_bindgen_test]
+async fn on_attribute_change_test() {
+    let values: Rc<std::cell::RefCell<Vec<Option<String>>>> =
+        Rc::new(std::cell::RefCell::new(Vec::new()));
+
+    let div = create_element("div");
+
+    let observer = on_attribute_change(&div, "data-state", {
+        let values = Rc::clone(&values);
+        move |value| values.borrow_mut().push(value)
+    });
+
+    div.set_attribute("data-state", "open").unwrap();
+    sleep(0).await;
+
+    // other attributes are ignored
+    div.set_attribute("data-other", "1").unwrap();
+    sleep(0).await;
+
+    div.remove_attribute("data-state").unwrap();
+    sleep(0).await;
+
+    assert_eq!(*values.borrow(), vec![Some("open".to_string()), None]);
+
+    observer.disconnect();
+
+    div.set_attribute("data-state", "closed").unwrap();
+    sleep(0).await;
+
+    assert_eq!(values.borrow().len(), 2);
+}
+
+#[wasm_bindgen_test]
+fn lock_scroll_unlock_scroll_test() {
+    let overflow = || body().style().get_property_value("overflow").unwrap();
+
+    body().style().set_property("overflow", "auto").unwrap();
+
+    lock_scroll();
+    lock_scroll();
+    assert_eq!(overflow(), "hidden");
+
+    // still locked by the first call
+    unlock_scroll();
+    assert_eq!(overflow(), "hidden");
+
+    // restored
+    unlock_scroll();
+    assert_eq!(overflow(), "auto");
+
+    // an extra unlock does nothing
+    unlock_scroll();
+    assert_eq!(overflow(), "auto");
+
+    body().style().remove_property("overflow").unwrap();
+}
+
+#[wasm_bindgen_test]
+fn is_form_valid_test() {
+    use web_sys::{HtmlFormElement, HtmlInputElement};
+
+    let form = create_element("form")
+        .dyn_into::<HtmlFormElement>()
+        .unwrap();
+    let input = create_element("input")
+        .dyn_into::<HtmlInputElement>()
+        .unwrap();
+    input.set_required(true);
+
+    form.append_child(&input).unwrap();
+    body().append_child(&form).unwrap();
+
+    // required but empty
+    assert!(!is_form_valid(&form));
+
+    input.set_value("filled");
+    assert!(is_form_valid(&form));
+    assert!(report_form_validity(&form));
+}
+
+#[wasm_bindgen_test]
+fn try_window_try_document_test() {
+    assert_eq!(try_window(), window());
+    assert!(try_window().is_some());
+
+    assert_eq!(try_document(), Some(document()));
+}
+
+#[wasm_bindgen_test]
+fn center_point_test() {
+    let div = create_element("div")
+        .dyn_ref::<HtmlElement>()
+        .unwrap()
+        .clone();
+    let style = div.style();
+    style.set_property("position", "fixed").unwrap();
+    style.set_property("left", "100px").unwrap();
+    style.set_property("top", "50px").unwrap();
+    style.set_property("width", "40px").unwrap();
+    style.set_property("height", "20px").unwrap();
+    body().append_child(&div).unwrap();
+
+    assert_eq!(center_point(&div), (120.0, 60.0));
+}
+
+#[wasm_bindgen_test]
+fn callback_with_arg_test() {
+    use web_sys::{InputEvent, KeyboardEvent, MouseEvent, MouseEventInit};
+
+    let button = create_element("button")
+        .dyn_ref::<HtmlElement>()
+        .unwrap()
+        .clone();
+    body().append_child(&button).unwrap();
+
+    let client_x = Rc::new(Cell::new(0));
+
+    let onclick = callback_with_arg({
+        let client_x = Rc::clone(&client_x);
+        move |event: MouseEvent| client_x.set(event.client_x())
+    });
+    button.set_onclick(Some(onclick.as_ref().unchecked_ref()));
+
+    let init = MouseEventInit::new();
+    init.set_client_x(42);
+    button
+        .dispatch_event(&MouseEvent::new_with_mouse_event_init_dict("click", &init).unwrap())
+        .unwrap();
+
+    assert_eq!(client_x.get(), 42);
+
+    button.set_onclick(None);
+
+    // the generic bounds work for the other common event types too
+    let _onkeydown = callback_with_arg(|event: KeyboardEvent| console_log!(event.key()));
+    let _oninput = callback_with_arg(|event: InputEvent| console_log!(event.data()));
+}
+
+#[wasm_bindgen_test]
+async fn timer_debug_test() {
+    set_timer_debug(true);
+
+    let fired = Rc::new(Cell::new(false));
+
+    set_timeout(
+        {
+            let fired = Rc::clone(&fired);
+            move || fired.set(true)
+        },
+        10,
+    )
+    .unwrap();
+
+    let cleared = Rc::new(Cell::new(false));
+
+    let timeout_id = set_timeout(
+        {
+            let cleared = Rc::clone(&cleared);
+            move || cleared.set(true)
+        },
+        10,
+    )
+    .unwrap();
+    clear_timeout(timeout_id);
+
+    let interval_id = set_interval(|| {}, 10).unwrap();
+    clear_interval(interval_id);
+
+    sleep(50).await;
+
+    set_timer_debug(false);
+
+    assert!(fired.get());
+    assert!(!cleared.get());
+}
+
+#[wasm_bindgen_test]
+async fn callback_once_test() {
+    let received = Rc::new(std::cell::RefCell::new(None));
+
+    // a non-`Copy` value which is moved out of the closure
+    let message = String::from("fired once");
+
+    let callback = callback_once({
+        let received = Rc::clone(&received);
+        move || *received.borrow_mut() = Some(message)
+    });
+
+    window()
+        .unwrap()
+        .set_timeout_with_callback_and_timeout_and_arguments_0(
+            callback.as_ref().unchecked_ref(),
+            10,
+        )
+        .unwrap();
+    callback.forget();
+
+    sleep(50).await;
+
+    assert_eq!(received.borrow().as_deref(), Some("fired once"));
+}
+
+#[wasm_bindgen_test]
+fn selection_range_test() {
+    let p = create_element("p");
+    p.set_inner_html("Hello world");
+    body().append_child(&p).unwrap();
+
+    // select the text "world"
+    let text = p.first_child().unwrap();
+    let range = document().create_range().unwrap();
+    range.set_start(&text, 6).unwrap();
+    range.set_end(&text, 11).unwrap();
+
+    let selection = window().unwrap().get_selection().unwrap().unwrap();
+    selection.remove_all_ranges().unwrap();
+    selection.add_range(&range).unwrap();
+
+    let range = selection_range().unwrap();
+    assert_eq!(range.to_string(), "world");
+
+    let bold = create_element("b");
+    surround_selection(&bold).unwrap();
+
+    assert_eq!(p.inner_html(), "Hello <b>world</b>");
+
+    selection.remove_all_ranges().unwrap();
+    assert!(selection_range().is_none());
+
+    p.remove();
+}
+
+#[wasm_bindgen_test]
+async fn interval_drop_test() {
+    let ticks = Rc::new(Cell::new(0));
+
+    {
+        let ticks = Rc::clone(&ticks);
+        // dropped at the end of the block
+        let _interval = Interval::start(move || ticks.set(ticks.get() + 1), 10);
+    }
+
+    let forgotten_ticks = Rc::new(Cell::new(0));
+
+    {
+        let forgotten_ticks = Rc::clone(&forgotten_ticks);
+        Interval::start(move || forgotten_ticks.set(forgotten_ticks.get() + 1), 10).forget();
+    }
+
+    sleep(50).await;
+
+    assert_eq!(ticks.get(), 0);
+    assert!(forgotten_ticks.get() > 0);
+}
+
+#[wasm_bindgen_test]
+fn bind_input_test() {
+    use web_sys::HtmlInputElement;
+
+    let input = create_element("input")
+        .dyn_into::<HtmlInputElement>()
+        .unwrap();
+    input.set_id("bind-input");
+    input.set_value("initial");
+    body().append_child(&input).unwrap();
+
+    let values = Rc::new(std::cell::RefCell::new(Vec::new()));
+
+    let binding = bind_input("bind-input", {
+        let values = Rc::clone(&values);
+        move |value| values.borrow_mut().push(value)
+    });
+
+    assert_eq!(*values.borrow(), vec!["initial".to_string()]);
+
+    input.set_value("changed");
+    input
+        .dispatch_event(&web_sys::Event::new("input").unwrap())
+        .unwrap();
+
+    assert_eq!(
+        *values.borrow(),
+        vec!["initial".to_string(), "changed".to_string()]
+    );
+
+    drop(binding);
+    input.remove();
+}
+
+#[wasm_bindgen_test]
+async fn timeout_has_fired_test() {
+    let timeout = Timeout::start(|| {}, 10);
+    assert!(!timeout.has_fired());
+
+    sleep(50).await;
+    assert!(timeout.has_fired());
+
+    // a dropped timeout never fires
+    let fired = Rc::new(Cell::new(false));
+
+    {
+        let fired = Rc::clone(&fired);
+        let _timeout = Timeout::start(move || fired.set(true), 10);
+    }
+
+    sleep(50).await;
+    assert!(!fired.get());
+}
+
+#[wasm_bindgen_test]
+fn format_bytes_test() {
+    assert_eq!(format_bytes(0), "0 B");
+    assert_eq!(format_bytes(1023), "1023 B");
+    assert_eq!(format_bytes(1024), "1.0 KB");
+    assert_eq!(format_bytes(1024 * 1024 - 1), "1.0 MB");
+    assert_eq!(format_bytes(1024 * 1024), "1.0 MB");
+    assert_eq!(format_bytes(1024 * 1024 * 1024), "1.0 GB");
+    assert_eq!(format_bytes(3 * 1024 * 1024 * 1024 / 2), "1.5 GB");
+    assert_eq!(format_bytes(2048 * 1024 * 1024 * 1024), "2048.0 GB");
+}
+
+#[wasm_bindgen_test]
+fn relative_time_test() {
+    let root = document().document_element().unwrap();
+    let lang = root.get_attribute("lang");
+    root.set_attribute("lang", "en").unwrap();
+
+    assert_eq!(relative_time(30), "30 seconds ago");
+    assert_eq!(relative_time(60), "1 minute ago");
+    assert_eq!(relative_time(3600), "1 hour ago");
+    assert_eq!(relative_time(2 * 86400), "2 days ago");
+    assert_eq!(relative_time(-300), "in 5 minutes");
+
+    match lang {
+        Some(lang) => root.set_attribute("lang", &lang).unwrap(),
+        None => root.remove_attribute("lang").unwrap(),
+    }
+}
+
+#[wasm_bindgen_test]
+async fn set_timeout_with_args_test() {
+    let received = Rc::new(std::cell::RefCell::new(String::new()));
+
+    set_timeout_with_args(
+        {
+            let received = Rc::clone(&received);
+            move |message: String| *received.borrow_mut() = message
+        },
+        10,
+        String::from("hello"),
+    )
+    .unwrap();
+
+    sleep(50).await;
+
+    assert_eq!(*received.borrow(), "hello");
+}
+
+#[wasm_bindgen_test]
+async fn request_animation_frame_test() {
+    let timestamp = Rc::new(Cell::new(0.0));
+
+    request_animation_frame({
+        let timestamp = Rc::clone(&timestamp);
+        move |time| timestamp.set(time)
+    })
+    .unwrap();
+
+    let cancelled = Rc::new(Cell::new(false));
+
+    let id = request_animation_frame({
+        let cancelled = Rc::clone(&cancelled);
+        move |_| cancelled.set(true)
+    })
+    .unwrap();
+    cancel_animation_frame(id);
+
+    sleep(100).await;
+
+    assert!(timestamp.get() > 0.0);
+    assert!(!cancelled.get());
+}
+
+#[wasm_bindgen_test]
+fn format_number_test() {
+    assert_eq!(format_number(1234.5, "en-US"), "1,234.5");
+    assert_eq!(format_number(1234567.0, "en-US"), "1,234,567");
+    assert_eq!(format_number(1234.5, "de-DE"), "1.234,5");
+
+    assert_eq!(format_currency(1234.5, "en-US", "USD"), "$1,234.50");
+}
+
+#[wasm_bindgen_test]
+fn create_html_element_test() {
+    let div = create_html_element("div");
+
+    div.style().set_property("color", "red").unwrap();
+
+    assert_eq!(div.tag_name(), "DIV");
+    assert_eq!(div.style().get_property_value("color").unwrap(), "red");
+}
+
+#[wasm_bindgen_test]
+fn timezone_test() {
+    let timezone = timezone();
+
+    // like "UTC" or "Europe/Berlin"
+    assert!(!timezone.is_empty());
+    assert!(!timezone.contains(' '));
+}
+
+#[wasm_bindgen_test]
+fn create_svg_element_test() {
+    let circle = create_svg_element("circle");
+
+    assert_eq!(
+        circle.namespace_uri().as_deref(),
+        Some("http://www.w3.org/2000/svg")
+    );
+    assert!(circle.dyn_ref::<HtmlElement>().is_none());
+
+    let math = create_element_ns("http://www.w3.org/1998/Math/MathML", "math");
+
+    assert_eq!(
+        math.namespace_uri().as_deref(),
+        Some("http://www.w3.org/1998/Math/MathML")
+    );
+}
+
+#[wasm_bindgen_test]
+fn on_color_scheme_change_test() {
+    let calls = Rc::new(Cell::new(0));
+
+    let listener = on_color_scheme_change({
+        let calls = Rc::clone(&calls);
+        move |_| calls.set(calls.get() + 1)
+    });
+
+    // the handler isn't called for the current preference
+    assert_eq!(calls.get(), 0);
+
+    listener.remove();
+}
+
+#[wasm_bindgen_test]
+fn open_file_picker_test() {
+    // NOTE: You need to test it manually
+    let button = create_html_element("button");
+    button.set_inner_html("Pick some files");
+
+    let onclick = callback(|| {
+        open_file_picker("", true, |files| {
+            for file in files {
+                console_log!("Selected file: ", file.name());
+            }
+        });
+    });
+    button.set_onclick(Some(onclick.as_ref().unchecked_ref()));
+    onclick.forget();
+
+    body().append_child(&button).unwrap();
+}
+
+#[wasm_bindgen_test]
+fn local_storage_test() {
+    local_storage_set("webru-test", "value").unwrap();
+    assert_eq!(local_storage_get("webru-test"), Some("value".to_string()));
+
+    local_storage_remove("webru-test");
+    assert_eq!(local_storage_get("webru-test"), None);
+
+    local_storage_set("webru-test", "value").unwrap();
+    local_storage_clear();
+    assert_eq!(local_storage_get("webru-test"), None);
+}
+
+#[wasm_bindgen_test]
+fn on_file_drop_test() {
+    use web_sys::{DataTransfer, DragEvent, DragEventInit, File};
+
+    let drop_zone = create_element("div");
+    body().append_child(&drop_zone).unwrap();
+
+    let names = Rc::new(std::cell::RefCell::new(Vec::new()));
+
+    let listener = on_file_drop(&drop_zone, {
+        let names = Rc::clone(&names);
+        move |files| {
+            for file in files {
+                names.borrow_mut().push(file.name());
+            }
+        }
+    });
+
+    let data = DataTransfer::new().unwrap();
+    for name in ["a.txt", "b.txt"] {
+        let parts = js_sys::Array::of1(&"content".into());
+        let file = File::new_with_str_sequence(&parts, name).unwrap();
+        data.items().add_with_file(&file).unwrap();
+    }
+
+    let init = DragEventInit::new();
+    init.set_data_transfer(Some(&data));
+    init.set_cancelable(true);
+    let event = DragEvent::new_with_event_init_dict("drop", &init).unwrap();
+
+    drop_zone.dispatch_event(&event).unwrap();
+
+    assert_eq!(*names.borrow(), vec!["a.txt", "b.txt"]);
+    assert!(event.default_prevented());
+
+    listener.remove();
+    drop_zone.remove();
+}
+
+#[wasm_bindgen_test]
+fn session_storage_test() {
+    session_storage_set("webru-test", "value").unwrap();
+    assert_eq!(session_storage_get("webru-test"), Some("value".to_string()));
+
+    // the two storages are separate
+    assert_eq!(local_storage_get("webru-test"), None);
+
+    session_storage_remove("webru-test");
+    assert_eq!(session_storage_get("webru-test"), None);
+
+    session_storage_set("webru-test", "value").unwrap();
+    session_storage_clear();
+    assert_eq!(session_storage_get("webru-test"), None);
+}
+
+#[wasm_bindgen_test]
+async fn raf_throttle_test() {
+    let calls = Rc::new(Cell::new(0));
+
+    let mut throttled = raf_throttle({
+        let calls = Rc::clone(&calls);
+        move || calls.set(calls.get() + 1)
+    });
+
+    for _ in 0..100 {
+        throttled();
+    }
+
+    // nothing runs before the frame
+    assert_eq!(calls.get(), 0);
+
+    sleep(100).await;
+    assert_eq!(calls.get(), 1);
+
+    // it can be scheduled again after the frame
+    throttled();
+    throttled();
+
+    sleep(100).await;
+    assert_eq!(calls.get(), 2);
+}
+
+#[wasm_bindgen_test]
+fn cookie_test() {
+    assert_eq!(get_cookie("webru-missing"), None);
+
+    // a value with `=`, `;` and spaces
+    set_cookie("webru-test", "a=b; c d", None);
+    set_cookie("webru-other", "other", None);
+
+    assert_eq!(get_cookie("webru-test"), Some("a=b; c d".to_string()));
+    assert_eq!(get_cookie("webru-other"), Some("other".to_string()));
+
+    // a prefix of the name doesn't match
+    assert_eq!(get_cookie("webru"), None);
+
+    set_cookie("webru-test", "", Some(0));
+    set_cookie("webru-other", "", Some(0));
+
+    assert_eq!(get_cookie("webru-test"), None);
+}
+
+#[wasm_bindgen_test]
+fn siblings_test() {
+    let list = create_element("ul");
+    list.set_inner_html("<li>a</li>text<li>b</li><li>c</li>");
+
+    let items = list.children();
+    let a = items.item(0).unwrap();
+    let b = items.item(1).unwrap();
+    let c = items.item(2).unwrap();
+
+    assert_eq!(siblings(&a), vec![b.clone(), c.clone()]);
+    assert_eq!(siblings(&b), vec![a.clone(), c.clone()]);
+    assert_eq!(siblings(&c), vec![a, b]);
+
+    // an element without a parent has no siblings
+    assert!(siblings(&list).is_empty());
+}
+
+#[wasm_bindgen_test]
+fn query_params_test() {
+    let history = window().unwrap().history().unwrap();
+    let url = url();
+
+    history
+        .replace_state_with_url(
+            &wasm_bindgen::JsValue::NULL,
+            "",
+            Some("?q=hello+world&page=2&tag=a&tag=b&debug"),
+        )
+        .unwrap();
+
+    let params = query_params();
+
+    assert_eq!(params.len(), 4);
+    assert_eq!(params["q"], "hello world");
+    assert_eq!(params["page"], "2");
+    // the last value of a repeated key is kept
+    assert_eq!(params["tag"], "b");
+    assert_eq!(params["debug"], "");
+
+    assert_eq!(query_param("page"), Some("2".to_string()));
+    assert_eq!(query_param("missing"), None);
+
+    history
+        .replace_state_with_url(&wasm_bindgen::JsValue::NULL, "", Some(&url))
+        .unwrap();
+}
+
+#[wasm_bindgen_test]
+fn walk_elements_test() {
+    let root = create_element("div");
+    root.set_inner_html(
+        "<section><h1>a</h1><p>b <b>c</b></p></section>text<footer><span>d</span></footer>",
+    );
+
+    let mut tags = Vec::new();
+    walk_elements(&root, |el| tags.push(el.tag_name()));
+
+    assert_eq!(tags, vec!["SECTION", "H1", "P", "B", "FOOTER", "SPAN"]);
+
+    // an element without children visits nothing
+    let mut count = 0;
+    walk_elements(&create_element("div"), |_| count += 1);
+    assert_eq!(count, 0);
+}
+
+#[wasm_bindgen_test]
+fn push_state_test() {
+    let url = url();
+
+    push_state("/new/path");
+    assert_eq!(path_name(), "/new/path");
+
+    replace_state("/other/path?page=2");
+    assert_eq!(path_name(), "/other/path");
+    assert_eq!(query_param("page"), Some("2".to_string()));
+
+    replace_state(&url);
+}
+
+#[wasm_bindgen_test]
+fn find_element_test() {
+    let root = create_element("div");
+    root.set_inner_html(
+        r#"<ul><li data-price="5">a</li><li data-price="20">b</li></ul><p data-price="30">c</p>"#,
+    );
+
+    let is_expensive = |el: &web_sys::Element| {
+        el.get_attribute("data-price")
+            .and_then(|price| price.parse::<u32>().ok())
+            .is_some_and(|price| price > 10)
+    };
+
+    // the first match in depth-first order
+    let found = find_element(&root, is_expensive).unwrap();
+    assert_eq!(found.inner_html(), "b");
+
+    assert!(find_element(&root, |el| el.tag_name() == "TABLE").is_none());
+}
+
+#[wasm_bindgen_test]
+async fn on_popstate_test() {
+    let url = url();
+    let path = Rc::new(std::cell::RefCell::new(String::new()));
+
+    let listener = on_popstate({
+        let path = Rc::clone(&path);
+        move || *path.borrow_mut() = path_name()
+    });
+
+    push_state("/first");
+    push_state("/second");
+
+    go_back();
+    sleep(100).await;
+
+    assert_eq!(*path.borrow(), "/first");
+
+    window()
+        .unwrap()
+        .remove_event_listener_with_callback("popstate", listener.as_ref().unchecked_ref())
+        .unwrap();
+
+    replace_state(&url);
+}
+
+#[wasm_bindgen_test]
+fn zoom_test() {
+    assert_eq!(get_zoom(), 1.0);
+
+    set_zoom(1.5);
+    assert_eq!(get_zoom(), 1.5);
+
+    set_zoom(1.0);
+    assert_eq!(get_zoom(), 1.0);
+}
+
+#[wasm_bindgen_test]
+fn add_event_listener_test() {
+    let button = create_element("button");
+    let clicks = Rc::new(Cell::new(0));
+
+    let listener = add_event_listener(&button, "click", {
+        let clicks = Rc::clone(&clicks);
+        move |event| {
+            assert_eq!(event.type_(), "click");
+            clicks.set(clicks.get() + 1);
+        }
+    });
+
+    let click = || {
+        button
+            .dispatch_event(&web_sys::Event::new("click").unwrap())
+            .unwrap();
+    };
+
+    click();
+    click();
+    assert_eq!(clicks.get(), 2);
+
+    listener.remove();
+
+    click();
+    assert_eq!(clicks.get(), 2);
+}
+
+#[wasm_bindgen_test]
+fn prevent_default_test() {
+    use web_sys::EventInit;
+
+    let parent = create_element("div");
+    let child = create_element("button");
+    parent.append_child(&child).unwrap();
+
+    let parent_clicks = Rc::new(Cell::new(0));
+
+    let _parent_listener = add_event_listener(&parent, "click", {
+        let parent_clicks = Rc::clone(&parent_clicks);
+        move |_| parent_clicks.set(parent_clicks.get() + 1)
+    });
+
+    let _child_listener = add_event_listener(&child, "click", |event| {
+        prevent_default(&event);
+        stop_propagation(&event);
+    });
+
+    let init = EventInit::new();
+    init.set_bubbles(true);
+    init.set_cancelable(true);
+    let event = web_sys::Event::new_with_event_init_dict("click", &init).unwrap();
+
+    child.dispatch_event(&event).unwrap();
+
+    assert!(event.default_prevented());
+    assert_eq!(parent_clicks.get(), 0);
+}
+
+#[wasm_bindgen_test]
+fn remove_element_test() {
+    let p = create_element("p");
+    p.set_id("remove-element-test");
+    body().append_child(&p).unwrap();
+
+    assert!(get_element_by_id("remove-element-test").is_some());
+
+    remove_element(&p);
+    assert!(get_element_by_id("remove-element-test").is_none());
+
+    // removing an element which is not in the DOM does nothing
+    remove_element(&p);
+}
+
+#[wasm_bindgen_test]
+fn replace_element_test() {
+    let old = create_element("p");
+    old.set_id("replace-element-old");
+    body().append_child(&old).unwrap();
+
+    let new = create_element("div");
+    new.set_id("replace-element-new");
+
+    replace_element(&old, &new).unwrap();
+
+    assert!(get_element_by_id("replace-element-old").is_none());
+    assert!(get_element_by_id("replace-element-new").is_some());
+
+    new.remove();
+}
+
+#[wasm_bindgen_test]
+fn hash_params_test() {
+    let url = url();
+
+    replace_state("#/search?q=hello+world&page=2");
+
+    let params = hash_params();
+    assert_eq!(params.len(), 2);
+    assert_eq!(params["q"], "hello world");
+    assert_eq!(params["page"], "2");
+
+    replace_state("#/about");
+    assert!(hash_params().is_empty());
+
+    replace_state(&url);
+}
+
+#[wasm_bindgen_test]
+fn append_three_children_test() {
+    let parent = create_element("div");
+
+    let h1 = create_element("h1");
+    let p = create_element("p");
+    let text = document().create_text_node("text");
+
+    append_children(&parent, &[&h1, &p, &text]).unwrap();
+
+    assert_eq!(parent.child_nodes().length(), 3);
+    assert_eq!(parent.inner_html(), "<h1></h1><p></p>text");
+}
+
+#[wasm_bindgen_test]
+async fn set_timeout_with_signal_test() {
+    use web_sys::AbortController;
+
+    let fired = Rc::new(Cell::new(0));
+    let controller = AbortController::new().unwrap();
+
+    for _ in 0..2 {
+        let fired = Rc::clone(&fired);
+        set_timeout_with_signal(move || fired.set(fired.get() + 1), 10, &controller.signal())
+            .unwrap();
+    }
+
+    controller.abort();
+
+    // an already aborted signal never lets the handler run
+    {
+        let fired = Rc::clone(&fired);
+        set_timeout_with_signal(move || fired.set(fired.get() + 1), 10, &controller.signal())
+            .unwrap();
+    }
+
+    // a signal which is not aborted lets the handler run
+    let other = AbortController::new().unwrap();
+    let other_fired = Rc::new(Cell::new(false));
+    {
+        let other_fired = Rc::clone(&other_fired);
+        set_timeout_with_signal(move || other_fired.set(true), 10, &other.signal()).unwrap();
+    }
+
+    sleep(50).await;
+
+    assert_eq!(fired.get(), 0);
+    assert!(other_fired.get());
+}
+
+#[wasm_bindgen_test]
+fn html_collection_iter_test() {
+    const CLASS: &str = "html-collection-iter-test";
+
+    let items: Vec<_> = (0..3)
+        .map(|_| {
+            let p = create_element("p");
+            p.set_class_name(CLASS);
+            body().append_child(&p).unwrap();
+            p
+        })
+        .collect();
+
+    let from_iter: Vec<_> = html_collection_iter(get_elements_by_classname(CLASS)).collect();
+
+    assert_eq!(from_iter, get_elements_by_classname_inside_vec(CLASS));
+    assert_eq!(from_iter, items);
+
+    for item in items {
+        item.remove();
+    }
+}
+
+#[wasm_bindgen_test]
+async fn fetch_text_abortable_test() {
+    use web_sys::AbortController;
+
+    let controller = AbortController::new().unwrap();
+    let signal = controller.signal();
+
+    // abort while the request is in flight
+    let abort = callback_once({
+        let controller = controller.clone();
+        move || controller.abort()
+    });
+    window()
+        .unwrap()
+        .set_timeout_with_callback_and_timeout_and_arguments_0(abort.as_ref().unchecked_ref(), 0)
+        .unwrap();
+    abort.forget();
+
+    let result = fetch_text_abortable(&url(), &signal).await;
+
+    assert!(result.is_err());
+    assert!(signal.aborted());
+
+    // the same request without aborting succeeds
+    let controller = AbortController::new().unwrap();
+    let text = fetch_text_abortable(&url(), &controller.signal())
+        .await
+        .unwrap();
+
+    assert!(!text.is_empty());
+}
+
+#[wasm_bindgen_test]
+fn node_list_iter_test() {
+    let list = create_element("ul");
+    list.set_inner_html("<li>a</li><li>b</li><li>c</li>");
+    list.set_id("node-list-iter-test");
+    body().append_child(&list).unwrap();
+
+    let selector = "#node-list-iter-test li";
+
+    let nodes = query_selector_all(selector);
+    let count = node_list_iter(nodes.clone()).count();
+
+    assert_eq!(count, nodes.length() as usize);
+    assert_eq!(count, 3);
+    assert_eq!(
+        node_list_iter(nodes).collect::<Vec<_>>(),
+        query_selector_all_inside_vec(selector)
+    );
+
+    list.remove();
+}
+
+#[wasm_bindgen_test]
+fn abort_controller_test() {
+    let controller = abort_controller();
+    assert!(!controller.signal().aborted());
+
+    controller.abort();
+    assert!(controller.signal().aborted());
+
+    let (controller, signal) = abort_controller_with_signal();
+    assert!(!signal.aborted());
+
+    controller.abort();
+    assert!(signal.aborted());
+}
+
+#[wasm_bindgen_test]
+fn download_blob_test() {
+    // NOTE: You need to test it manually. Downloads can't be checked in a headless browser
+    download_blob(b"name,age\nShanto,17\n", "people.csv", "text/csv").unwrap();
+}
+
+#[wasm_bindgen_test]
+fn form_to_map_test() {
+    use web_sys::HtmlFormElement;
+
+    let form = create_element("form")
+        .dyn_into::<HtmlFormElement>()
+        .unwrap();
+    form.set_inner_html(
+        r#"
+        <input name="username" value="shanto">
+        <input name="email" value="shanto@example.com">
+        <input type="checkbox" name="tag" value="rust" checked>
+        <input type="checkbox" name="tag" value="wasm" checked>
+        <input type="checkbox" name="tag" value="js">
+        <input name="disabled" value="x" disabled>
+        "#,
+    );
+
+    let map = form_to_map(&form);
+
+    assert_eq!(map.len(), 3);
+    assert_eq!(map["username"], "shanto");
+    assert_eq!(map["email"], "shanto@example.com");
+    assert_eq!(map["tag"], "wasm");
+
+    let tags: Vec<_> = form_to_pairs(&form)
+        .into_iter()
+        .filter(|(name, _)| name == "tag")
+        .map(|(_, value)| value)
+        .collect();
+
+    assert_eq!(tags, vec!["rust", "wasm"]);
+}
+
+#[wasm_bindgen_test]
+fn language_test() {
+    let language = language();
+    assert!(language.is_some());
+
+    let languages = languages();
+    if let Some(first) = languages.first() {
+        assert!(!first.is_empty());
+    }
+}
+
+#[wasm_bindgen_test]
+fn is_online_test() {
+    let online = is_online();
+    console_log!("online: ", online);
+
+    let events = Rc::new(Cell::new(0));
+
+    let on_online = on_online({
+        let events = Rc::clone(&events);
+        move || events.set(events.get() + 1)
+    });
+    let on_offline = on_offline({
+        let events = Rc::clone(&events);
+        move || events.set(events.get() + 1)
+    });
+
+    let window = window().unwrap();
+    window
+        .dispatch_event(&web_sys::Event::new("online").unwrap())
+        .unwrap();
+    window
+        .dispatch_event(&web_sys::Event::new("offline").unwrap())
+        .unwrap();
+
+    assert_eq!(events.get(), 2);
+
+    window
+        .remove_event_listener_with_callback("online", on_online.as_ref().unchecked_ref())
+        .unwrap();
+    window
+        .remove_event_listener_with_callback("offline", on_offline.as_ref().unchecked_ref())
+        .unwrap();
+}
+
+#[wasm_bindgen_test]
+async fn get_current_position_test() {
+    // NOTE: You need to test it manually. The browser asks for the permission first
+    match get_current_position().await {
+        Ok((latitude, longitude)) => {
+            let msg = format!("position: {}, {}", latitude, longitude);
+            console_log!(msg);
+        }
+        Err(error) => console_log!("couldn't get the position: ", error),
+    }
+}
+
+#[wasm_bindgen_test]
+fn set_html_test() {
+    let div = create_element("div");
+
+    set_html(&div, "<b>bold</b> text");
+    assert_eq!(get_html(&div), "<b>bold</b> text");
+    assert_eq!(div.child_element_count(), 1);
+
+    // `set_text` doesn't parse the HTML
+    set_text(&div, "<b>bold</b> text");
+    assert_eq!(div.child_element_count(), 0);
+    assert_eq!(get_html(&div), "&lt;b&gt;bold&lt;/b&gt; text");
+}
+
+#[wasm_bindgen_test]
+fn parse_html_test() {
+    let fragment = parse_html("<li>a</li><li>b</li>");
+
+    assert_eq!(fragment.child_element_count(), 2);
+    assert_eq!(fragment.first_element_child().unwrap().inner_html(), "a");
+    assert_eq!(fragment.last_element_child().unwrap().inner_html(), "b");
+
+    // the nodes are not in the document
+    assert!(fragment
+        .first_element_child()
+        .unwrap()
+        .parent_element()
+        .is_none());
+}
+
+#[wasm_bindgen_test]
+fn clone_template_test() {
+    let template = create_element("template")
+        .dyn_into::<web_sys::HtmlTemplateElement>()
+        .unwrap();
+    template.set_inner_html("<li><span>item</span></li>");
+
+    let first = clone_template(&template);
+    let second = clone_template(&template);
+
+    first
+        .query_selector("span")
+        .unwrap()
+        .unwrap()
+        .set_text_content(Some("first"));
+
+    // the clones are independent of each other and of the template
+    assert_eq!(
+        first.query_selector("span").unwrap().unwrap().inner_html(),
+        "first"
+    );
+    assert_eq!(
+        second.query_selector("span").unwrap().unwrap().inner_html(),
+        "item"
+    );
+    assert_eq!(get_html(&template), "<li><span>item</span></li>");
+}
+
+#[wasm_bindgen_test]
+fn set_styles_test() {
+    let div = create_element("div").dyn_into::<HtmlElement>().unwrap();
+
+    set_styles(
+        &div,
+        &[
+            ("display", "flex"),
+            ("color", "red"),
+            ("width", "20px"),
+            ("not-a-property", "1"),
+        ],
+    );
+
+    let style = div.style();
+    assert_eq!(style.get_property_value("display").unwrap(), "flex");
+    assert_eq!(style.get_property_value("color").unwrap(), "red");
+    assert_eq!(style.get_property_value("width").unwrap(), "20px");
+    assert_eq!(style.get_property_value("not-a-property").unwrap(), "");
+}
+
+#[wasm_bindgen_test]
+fn on_click_test() {
+    let button = create_element("button").dyn_into::<HtmlElement>().unwrap();
+    let clicks = Rc::new(Cell::new(0));
+
+    let remove = on_click(&button, {
+        let clicks = Rc::clone(&clicks);
+        move || clicks.set(clicks.get() + 1)
+    });
+
+    button.click();
+    assert_eq!(clicks.get(), 1);
+
+    remove();
+
+    button.click();
+    assert_eq!(clicks.get(), 1);
+}
+
+#[wasm_bindgen_test]
+fn on_input_test() {
+    let input = create_element("input")
+        .dyn_into::<web_sys::HtmlInputElement>()
+        .unwrap();
+    let value = Rc::new(std::cell::RefCell::new(String::new()));
+    let changes = Rc::new(Cell::new(0));
+
+    let input_listener = on_input(&input, {
+        let value = Rc::clone(&value);
+        move |new_value| *value.borrow_mut() = new_value
+    });
+    let change_listener = on_change(&input, {
+        let changes = Rc::clone(&changes);
+        move |_| changes.set(changes.get() + 1)
+    });
+
+    input.set_value("hello");
+    input
+        .dispatch_event(&web_sys::Event::new("input").unwrap())
+        .unwrap();
+
+    assert_eq!(*value.borrow(), "hello");
+    assert_eq!(changes.get(), 0);
+
+    input
+        .dispatch_event(&web_sys::Event::new("change").unwrap())
+        .unwrap();
+
+    assert_eq!(changes.get(), 1);
+
+    input
+        .remove_event_listener_with_callback("input", input_listener.as_ref().unchecked_ref())
+        .unwrap();
+    input
+        .remove_event_listener_with_callback("change", change_listener.as_ref().unchecked_ref())
+        .unwrap();
+}
+
+#[wasm_bindgen_test]
+fn on_submit_test() {
+    let form = create_element("form")
+        .dyn_into::<web_sys::HtmlFormElement>()
+        .unwrap();
+    let submitted = Rc::new(Cell::new(false));
+
+    let listener = on_submit(&form, {
+        let submitted = Rc::clone(&submitted);
+        move || submitted.set(true)
+    });
+
+    let init = web_sys::EventInit::new();
+    init.set_cancelable(true);
+    let event = web_sys::Event::new_with_event_init_dict("submit", &init).unwrap();
+
+    form.dispatch_event(&event).unwrap();
+
+    assert!(submitted.get());
+    assert!(event.default_prevented());
+
+    form.remove_event_listener_with_callback("submit", listener.as_ref().unchecked_ref())
+        .unwrap();
+}
+
+#[wasm_bindgen_test]
+fn on_keydown_test() {
+    use web_sys::{KeyboardEvent, KeyboardEventInit};
+
+    let input = create_element("input");
+    let keys = Rc::new(std::cell::RefCell::new(Vec::new()));
+
+    let keydown = on_keydown(&input, {
+        let keys = Rc::clone(&keys);
+        move |key| keys.borrow_mut().push(format!("down {}", key))
+    });
+    let keyup = on_keyup(&input, {
+        let keys = Rc::clone(&keys);
+        move |key| keys.borrow_mut().push(format!("up {}", key))
+    });
+
+    let init = KeyboardEventInit::new();
+    init.set_key("Escape");
+
+    input
+        .dispatch_event(
+            &KeyboardEvent::new_with_keyboard_event_init_dict("keydown", &init).unwrap(),
+        )
+        .unwrap();
+    input
+        .dispatch_event(&KeyboardEvent::new_with_keyboard_event_init_dict("keyup", &init).unwrap())
+        .unwrap();
+
+    assert_eq!(*keys.borrow(), ["down Escape", "up Escape"]);
+
+    input
+        .remove_event_listener_with_callback("keydown", keydown.as_ref().unchecked_ref())
+        .unwrap();
+    input
+        .remove_event_listener_with_callback("keyup", keyup.as_ref().unchecked_ref())
+        .unwrap();
+}
+
+#[wasm_bindgen_test]
+async fn until_ready_test() {
+    // the document is already loaded in the test runner, so this must not hang
+    until_ready().await;
+
+    assert_ne!(ready_state(), "loading");
+}
+
+#[wasm_bindgen_test]
+fn visibility_test() {
+    let state = visibility_state();
+
+    assert!(!state.is_empty());
+    assert_eq!(is_hidden(), state == "hidden");
+
+    let changes = Rc::new(Cell::new(0));
+
+    let listener = on_visibility_change({
+        let changes = Rc::clone(&changes);
+        move |_| changes.set(changes.get() + 1)
+    });
+
+    document()
+        .dispatch_event(&web_sys::Event::new("visibilitychange").unwrap())
+        .unwrap();
+
+    assert_eq!(changes.get(), 1);
+
+    listener.remove();
+}
+
+#[wasm_bindgen_test]
+async fn set_interval_immediate_test() {
+    let ticks = Rc::new(Cell::new(0));
+
+    let interval_id = set_interval_immediate(
+        {
+            let ticks = Rc::clone(&ticks);
+            move || ticks.set(ticks.get() + 1)
+        },
+        50,
+    )
+    .unwrap();
+
+    // the first tick is synchronous
+    assert!(ticks.get() >= 1);
+
+    sleep(120).await;
+    clear_interval(interval_id);
+
+    assert!(ticks.get() >= 2);
+}
+
+#[wasm_bindgen_test]
+async fn countdown_test() {
+    let ticks = Rc::new(std::cell::RefCell::new(Vec::new()));
+    let done = Rc::new(Cell::new(0));
+
+    let _countdown = Countdown::start(
+        2,
+        {
+            let ticks = Rc::clone(&ticks);
+            move |remaining| ticks.borrow_mut().push(remaining)
+        },
+        {
+            let done = Rc::clone(&done);
+            move || done.set(done.get() + 1)
+        },
+    );
+
+    sleep(1500).await;
+    assert_eq!(*ticks.borrow(), [1]);
+    assert_eq!(done.get(), 0);
+
+    sleep(1500).await;
+    assert_eq!(*ticks.borrow(), [1, 0]);
+    assert_eq!(done.get(), 1);
+
+    // the countdown doesn't continue after zero
+    sleep(1200).await;
+    assert_eq!(ticks.borrow().len(), 2);
+    assert_eq!(done.get(), 1);
+}
+
+#[wasm_bindgen_test]
+fn meta_content_test() {
+    let meta = create_element("meta");
+    meta.set_attribute("name", "meta-content-test").unwrap();
+    meta.set_attribute("content", "secret-token").unwrap();
+    document().head().unwrap().append_child(&meta).unwrap();
+
+    assert_eq!(
+        meta_content("meta-content-test"),
+        Some("secret-token".to_string())
+    );
+    assert_eq!(meta_content("missing-meta-content-test"), None);
+
+    // quotes and control characters are fine in the name
+    assert_eq!(meta_content("a\"b"), None);
+    assert_eq!(meta_content("a\nb"), None);
+
+    meta.remove();
+}
+
+#[wasm_bindgen_test]
+fn set_favicon_test() {
+    set_favicon("/first-icon.png");
+
+    let icons = query_selector_all_inside_vec("link[rel~=\"icon\"]");
+    assert_eq!(icons.len(), 1);
+
+    // an existing icon link is reused
+    set_favicon("/second-icon.png");
+
+    let icons = query_selector_all_inside_vec("link[rel~=\"icon\"]");
+    assert_eq!(icons.len(), 1);
+
+    let icon = icons[0].dyn_ref::<web_sys::Element>().unwrap();
+    assert_eq!(icon.get_attribute("href").unwrap(), "/second-icon.png");
+
+    icon.remove();
+}
+
+#[wasm_bindgen_test]
+fn inject_style_test() {
+    let div = create_element("div");
+    div.set_class_name("inject-style-test");
+    body().append_child(&div).unwrap();
+
+    let style = inject_style(".inject-style-test { margin-left: 17px; }");
+
+    assert_eq!(
+        computed_styles(&div, &["margin-left"])["margin-left"],
+        "17px"
+    );
+
+    // removing the element removes the rules
+    style.remove();
+
+    assert_eq!(
+        computed_styles(&div, &["margin-left"])["margin-left"],
+        "0px"
+    );
+
+    div.remove();
+}
+
+#[wasm_bindgen_test]
+async fn load_script_test() {
+    load_script("data:text/javascript,window.webruLoadScriptTest = 42;")
+        .await
+        .unwrap();
+
+    let value = js_sys::Reflect::get(&window().unwrap(), &"webruLoadScriptTest".into()).unwrap();
+    assert_eq!(value.as_f64(), Some(42.0));
+
+    assert!(load_script("/this-script-does-not-exist.js").await.is_err());
+}
+
+#[wasm_bindgen_test]
+fn selected_text_test() {
+    clear_selection();
+    assert_eq!(selected_text(), "");
+
+    let p = create_element("p");
+    p.set_inner_html("selected text");
+    body().append_child(&p).unwrap();
+
+    let selection = window().unwrap().get_selection().unwrap().unwrap();
+    selection.select_all_children(&p).unwrap();
+
+    assert_eq!(selected_text(), "selected text");
+
+    clear_selection();
+    assert_eq!(selected_text(), "");
+
+    p.remove();
+}
+
+#[wasm_bindgen_test]
+async fn window_message_test() {
+    let received = Rc::new(std::cell::RefCell::new(None));
+
+    let listener = on_window_message({
+        let received = Rc::clone(&received);
+        move |data, origin| *received.borrow_mut() = Some((data, origin))
+    });
+
+    let window = window().unwrap();
+    let origin = window.location().origin().unwrap();
+
+    post_message_to(&window, "hello", &origin).unwrap();
+
+    // messages are delivered asynchronously
+    sleep(100).await;
+
+    assert_eq!(*received.borrow(), Some(("hello".to_string(), origin)));
+
+    window
+        .remove_event_listener_with_callback("message", listener.as_ref().unchecked_ref())
+        .unwrap();
+}
+
+#[wasm_bindgen_test]
+fn random_bytes_test() {
+    let bytes = random_bytes(16).unwrap();
+
+    assert_eq!(bytes.len(), 16);
+    assert!(bytes.iter().any(|&byte| byte != 0));
+
+    // more than one `getRandomValues()` call can fill
+    let bytes = random_bytes(100_000).unwrap();
+
+    assert_eq!(bytes.len(), 100_000);
+    assert!(bytes[65536..].iter().any(|&byte| byte != 0));
+
+    assert!(random_bytes(0).unwrap().is_empty());
+}
+
+#[wasm_bindgen_test]
+fn query_filter_test() {
+    let container = create_element("div");
+    container.set_id("query-filter-test");
+    container.set_inner_html(
+        "<p class=\"keep\">1</p><p>2</p><p class=\"keep highlighted\">3</p><span class=\"keep\">4</span>",
+    );
+    body().append_child(&container).unwrap();
+
+    let kept = query_filter("#query-filter-test p", |el| {
+        el.class_list().contains("keep")
+    });
+
+    assert_eq!(kept.len(), 2);
+    assert_eq!(kept[0].inner_html(), "1");
+    assert_eq!(kept[1].inner_html(), "3");
+
+    container.remove();
+}
+
+#[wasm_bindgen_test]
+fn children_test() {
+    let parent = create_element("ul");
+
+    for text in ["a", "b", "c"] {
+        let li = create_element("li");
+        li.set_inner_html(text);
+        parent.append_child(&li).unwrap();
+    }
+
+    let text = document().create_text_node("not an element");
+    parent.append_child(&text).unwrap();
+
+    let items = children(&parent);
+    assert_eq!(items.len(), 3);
+    assert_eq!(items[1].inner_html(), "b");
+
+    assert_eq!(first_child_element(&parent).unwrap().inner_html(), "a");
+    // the text node is skipped
+    assert_eq!(last_child_element(&parent).unwrap().inner_html(), "c");
+
+    let empty = create_element("ul");
+    assert!(children(&empty).is_empty());
+    assert!(first_child_element(&empty).is_none());
+    assert!(last_child_element(&empty).is_none());
+}
+
+#[wasm_bindgen_test]
+fn sibling_navigation_test() {
+    let list = create_element("ul");
+    list.set_inner_html("<li>a</li> <li>b</li> <li>c</li>");
+
+    let items = children(&list);
+    let (first, middle, last) = (&items[0], &items[1], &items[2]);
+
+    assert_eq!(parent(middle).as_ref(), Some(&list));
+
+    // the whitespace text nodes are skipped
+    assert_eq!(next_sibling_element(first).as_ref(), Some(middle));
+    assert_eq!(prev_sibling_element(last).as_ref(), Some(middle));
+    assert!(prev_sibling_element(first).is_none());
+    assert!(next_sibling_element(last).is_none());
+
+    assert_eq!(siblings(middle), [first.clone(), last.clone()]);
+
+    // a detached element has no parent or siblings
+    let detached = create_element("li");
+    assert!(parent(&detached).is_none());
+    assert!(next_sibling_element(&detached).is_none());
+    assert!(siblings(&detached).is_empty());
+}
+
+#[wasm_bindgen_test]
+async fn set_timeout_owned_test() {
+    let fired = Rc::new(Cell::new(0));
+
+    let timeout = set_timeout_owned(
+        {
+            let fired = Rc::clone(&fired);
+            move || fired.set(fired.get() + 1)
+        },
+        50,
+    )
+    .unwrap();
+
+    // dropping the timeout before it fires cancels it
+    drop(timeout);
+    sleep(100).await;
+    assert_eq!(fired.get(), 0);
+
+    let _timeout = set_timeout_owned(
+        {
+            let fired = Rc::clone(&fired);
+            move || fired.set(fired.get() + 1)
+        },
+        50,
+    )
+    .unwrap();
+
+    sleep(100).await;
+    assert_eq!(fired.get(), 1);
+}
+
+#[wasm_bindgen_test]
+fn rect_relative_to_test() {
+    let container = create_element("div").dyn_into::<HtmlElement>().unwrap();
+    set_styles(
+        &container,
+        &[
+            ("position", "relative"),
+            ("margin", "40px"),
+            ("padding", "0"),
+        ],
+    );
+
+    let child = create_element("div").dyn_into::<HtmlElement>().unwrap();
+    set_styles(
+        &child,
+        &[
+            ("position", "absolute"),
+            ("left", "25px"),
+            ("top", "15px"),
+            ("width", "10px"),
+            ("height", "10px"),
+        ],
+    );
+
+    container.append_child(&child).unwrap();
+    body().append_child(&container).unwrap();
+
+    assert_eq!(rect_relative_to(&child, &container), (25.0, 15.0));
+
+    container.remove();
+}
+
+#[wasm_bindgen_test]
+fn insert_adjacent_html_test() {
+    let container = create_element("div");
+    container.set_inner_html("<p>content</p>");
+
+    let p = first_child_element(&container).unwrap();
+
+    insert_adjacent_html(&p, AdjacentPosition::BeforeBegin, "<i>1</i>").unwrap();
+    insert_adjacent_html(&p, AdjacentPosition::AfterBegin, "<i>2</i>").unwrap();
+    insert_adjacent_html(&p, AdjacentPosition::BeforeEnd, "<i>3</i>").unwrap();
+    insert_adjacent_html(&p, AdjacentPosition::AfterEnd, "<i>4</i>").unwrap();
+
+    assert_eq!(
+        get_html(&container),
+        "<i>1</i><p><i>2</i>content<i>3</i></p><i>4</i>"
+    );
+
+    // an element without a parent has no outside positions
+    let detached = create_element("p");
+    assert!(insert_adjacent_html(&detached, AdjacentPosition::AfterEnd, "<i></i>").is_err());
+    assert!(insert_adjacent_html(&detached, AdjacentPosition::BeforeEnd, "<i></i>").is_ok());
+}
+
+#[wasm_bindgen_test]
+fn batch_update_test() {
+    let div = create_element("div").dyn_into::<HtmlElement>().unwrap();
+
+    // no inline display
+    batch_update(&div, || {
+        assert_eq!(div.style().get_property_value("display").unwrap(), "none");
+        div.append_child(&create_element("span")).unwrap();
+    });
+
+    assert_eq!(div.style().get_property_value("display").unwrap(), "");
+    assert_eq!(div.child_element_count(), 1);
+
+    // an inline display and its priority are restored
+    div.style()
+        .set_property_with_priority("display", "flex", "important")
+        .unwrap();
+
+    batch_update(&div, || {});
+
+    assert_eq!(div.style().get_property_value("display").unwrap(), "flex");
+    assert_eq!(div.style().get_property_priority("display"), "important");
+}
+
+#[wasm_bindgen_test]
+async fn countdown_forget_test() {
+    let done = Rc::new(Cell::new(false));
+
+    {
+        let done = Rc::clone(&done);
+        Countdown::start(1, |_| {}, move || done.set(true)).forget();
+    }
+
+    sleep(1500).await;
+    assert!(done.get());
+}